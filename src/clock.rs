@@ -0,0 +1,236 @@
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use thiserror::Error;
+
+use crate::instant::{SystemTimeConversionError, Tick};
+use crate::rounding::Rounding;
+use crate::scale::{ScaleConversion, Seconds};
+use crate::zoneinfo::{get_leap_second_adjustment_for_instant_seconds, TAI_MINUS_UTC_AT_1972};
+use crate::{Duration, Instant, InstantNs128, Nanoseconds, Scale};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ClockError {
+    #[error("current time is out of range for the instant type")]
+    OutOfRange,
+    #[error("clock is not available on this system")]
+    Unavailable,
+    #[error("leap-second data is not available")]
+    MissingLeapSecondData,
+    #[error("the kernel's TAI offset has not been set")]
+    TaiOffsetUnknown,
+    #[error("the kernel's TAI offset is {kernel} seconds but should be {expected} seconds")]
+    TaiOffsetMismatch { kernel: i32, expected: i32 },
+    #[error("failed to convert the system time: {0}")]
+    SystemTime(SystemTimeConversionError),
+}
+
+/// A source of the current time.
+pub trait Clock {
+    /// Returns the current instant, truncated to the scale S.
+    fn now<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, ClockError>;
+}
+
+/// The system's real-time clock, as returned by [SystemTime::now]. Whether the system clock
+/// counts leap seconds or not is detected at runtime, see `TryFrom<SystemTime>` for [Instant].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, ClockError> {
+        SystemTime::now().try_into().map_err(|error| match error {
+            SystemTimeConversionError::OutOfRange => ClockError::OutOfRange,
+            error => ClockError::SystemTime(error),
+        })
+    }
+}
+
+/// A clock that reads the Linux CLOCK_TAI clock. CLOCK_TAI only differs from the real-time clock
+/// if something (typically an NTP or PTP daemon) has told the kernel the current TAI − UTC
+/// offset, so the offset is checked against the leap-second table when the clock is created.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy)]
+pub struct TaiClock {
+    _private: (),
+}
+
+#[cfg(target_os = "linux")]
+impl TaiClock {
+    pub fn new() -> Result<Self, ClockError> {
+        // With modes set to zero, adjtimex only reads the kernel's time parameters.
+        // SAFETY: timex is a plain C struct of integers, for which all zeroes is a valid value.
+        let mut timex: libc::timex = unsafe { std::mem::zeroed() };
+        // SAFETY: timex is a valid, exclusively borrowed timex for the duration of the call, and
+        // with modes set to zero the kernel only writes to it.
+        if unsafe { libc::adjtimex(&mut timex) } == -1 {
+            return Err(ClockError::Unavailable);
+        }
+        let kernel = timex.tai as i32;
+        if kernel == 0 {
+            return Err(ClockError::TaiOffsetUnknown);
+        }
+
+        let now: Instant<i64, Seconds> = SystemClock.now()?;
+        let leap_seconds = get_leap_second_adjustment_for_instant_seconds(now.ticks_since_epoch())
+            .ok_or(ClockError::MissingLeapSecondData)?;
+        let expected = TAI_MINUS_UTC_AT_1972 + leap_seconds;
+        if kernel != expected {
+            return Err(ClockError::TaiOffsetMismatch { kernel, expected });
+        }
+        Ok(TaiClock { _private: () })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Clock for TaiClock {
+    fn now<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, ClockError> {
+        let mut timespec = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: timespec is a valid, exclusively borrowed timespec for the duration of the call.
+        if unsafe { libc::clock_gettime(libc::CLOCK_TAI, &mut timespec) } == -1 {
+            return Err(ClockError::Unavailable);
+        }
+        // CLOCK_TAI counts from 1970-01-01 00:00:00 TAI, which is a fixed offset from the
+        // Instant epoch.
+        let seconds = timespec.tv_sec as i128 - TAI_MINUS_UTC_AT_1972 as i128;
+        let nanoseconds = seconds * 1_000_000_000 + timespec.tv_nsec as i128;
+        from_nanoseconds(InstantNs128::from_ticks_since_epoch(nanoseconds))
+    }
+}
+
+/// A clock that never goes backwards. It reads the system clock once when created and then
+/// advances with the monotonic clock of the system, so it's unaffected by later adjustments
+/// of the system clock (but will drift from it if the system clock is corrected).
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    start: InstantNs128,
+    start_monotonic: std::time::Instant,
+}
+
+impl MonotonicClock {
+    pub fn new() -> Result<Self, ClockError> {
+        Ok(MonotonicClock {
+            start: SystemClock.now()?,
+            start_monotonic: std::time::Instant::now(),
+        })
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, ClockError> {
+        let elapsed = self.start_monotonic.elapsed().as_nanos() as i128;
+        from_nanoseconds(
+            self.start
+                .checked_add(Duration::<i128, Nanoseconds>::new(elapsed))
+                .ok_or(ClockError::OutOfRange)?,
+        )
+    }
+}
+
+/// A clock that only changes when told to, for use in tests.
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<InstantNs128>,
+}
+
+impl MockClock {
+    pub fn new<T: Tick, S: Scale>(now: Instant<T, S>) -> Self {
+        MockClock {
+            now: Mutex::new(to_nanoseconds(now)),
+        }
+    }
+
+    pub fn set<T: Tick, S: Scale>(&self, now: Instant<T, S>) {
+        *self.now.lock().unwrap() = to_nanoseconds(now);
+    }
+
+    pub fn advance<T: Tick, S: Scale>(&self, duration: Duration<T, S>) {
+        let duration = Duration::<i128, Nanoseconds>::new(
+            duration
+                .ticks()
+                .to_i128()
+                .and_then(|ticks| {
+                    ScaleConversion::<S, Nanoseconds>::convert(ticks, Rounding::Floor)
+                })
+                .expect("duration is too large for the mock clock"),
+        );
+        let mut now = self.now.lock().unwrap();
+        *now = now
+            .checked_add(duration)
+            .expect("mock clock advanced out of range");
+    }
+}
+
+impl Clock for MockClock {
+    fn now<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, ClockError> {
+        from_nanoseconds(*self.now.lock().unwrap())
+    }
+}
+
+// The clocks keep time in nanoseconds internally, and round down to coarser scales. Finer scales
+// are supported too, but the extra ticks are always zero.
+fn from_nanoseconds<T: Tick, S: Scale>(instant: InstantNs128) -> Result<Instant<T, S>, ClockError> {
+    let ticks =
+        ScaleConversion::<Nanoseconds, S>::convert(instant.ticks_since_epoch(), Rounding::Floor)
+            .and_then(T::from)
+            .ok_or(ClockError::OutOfRange)?;
+    Ok(Instant::from_ticks_since_epoch(ticks))
+}
+
+fn to_nanoseconds<T: Tick, S: Scale>(instant: Instant<T, S>) -> InstantNs128 {
+    let ticks = instant
+        .ticks_since_epoch()
+        .to_i128()
+        .and_then(|ticks| ScaleConversion::<S, Nanoseconds>::convert(ticks, Rounding::Floor))
+        .expect("instant is too large for the mock clock");
+    InstantNs128::from_ticks_since_epoch(ticks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instant::InstantS64;
+
+    #[test]
+    fn mock_clock() {
+        let clock = MockClock::new(InstantS64::from_ticks_since_epoch(1_700_000_000));
+        let now: InstantNs128 = clock.now().unwrap();
+        assert_eq!(now.ticks_since_epoch(), 1_700_000_000_000_000_000);
+
+        clock.advance(Duration::<i32, Nanoseconds>::new(1_500_000_000));
+        let now: InstantS64 = clock.now().unwrap();
+        assert_eq!(now.ticks_since_epoch(), 1_700_000_001);
+
+        clock.set(InstantNs128::from_ticks_since_epoch(-1));
+        let now: InstantS64 = clock.now().unwrap();
+        assert_eq!(now.ticks_since_epoch(), -1);
+        assert_eq!(clock.now::<u64, Seconds>(), Err(ClockError::OutOfRange));
+    }
+
+    #[test]
+    fn monotonic_clock() {
+        let clock = MonotonicClock::new().unwrap();
+        let t1: InstantNs128 = clock.now().unwrap();
+        let t2: InstantNs128 = clock.now().unwrap();
+        assert!(t1 <= t2);
+        let system: InstantNs128 = SystemClock.now().unwrap();
+        assert!((system - t2).ticks().abs() < 1_000_000_000);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn tai_clock() {
+        // Whether the TAI offset is set depends on the system running the test.
+        match TaiClock::new() {
+            Ok(clock) => {
+                let tai: InstantS64 = clock.now().unwrap();
+                let system: InstantS64 = SystemClock.now().unwrap();
+                assert!((system - tai).ticks().abs() <= 1);
+            }
+            Err(ClockError::TaiOffsetUnknown) | Err(ClockError::TaiOffsetMismatch { .. }) => {}
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 
+use num_integer::Integer;
 use num_rational::Ratio;
-use num_traits::PrimInt;
+use num_traits::{CheckedAdd, CheckedSub, PrimInt, SaturatingMul};
+use thiserror::Error;
 
 use crate::instant::Tick;
-use crate::scale::{Milliseconds, Scale, Seconds};
+use crate::least_common_width::{CommonTick, LeastCommonWidth};
+use crate::rounding::{div_round_by, mul_div_exact, mul_div_round, Rounding};
+use crate::scale::{CommonScale, FinerScale, Milliseconds, Scale, ScaleConversion, Seconds};
 use crate::widen::Widen;
 use crate::{Instant, InstantOutOfRange, Nanoseconds};
 
@@ -33,37 +37,22 @@ pub type DurationS128 = Duration<i128, Seconds>;
 pub type DurationMs128 = Duration<i128, Milliseconds>;
 pub type DurationNs128 = Duration<i128, Nanoseconds>;
 
+#[derive(Error, Debug)]
+#[error("duration is out of range")]
+pub struct DurationOutOfRange;
+
+// The result of adding or subtracting a Duration<T1, S1> and a Duration<T2, S2>.
+pub(crate) type CommonDuration<T1, S1, T2, S2> = Duration<CommonTick<T1, T2>, CommonScale<S1, S2>>;
+
 impl<T: Tick, S: Scale> Duration<T, S> {
-    pub(crate) fn new(t: T) -> Self {
+    /// Creates a duration of the given number of ticks of the scale S.
+    pub fn new(t: T) -> Self {
         Self {
             ticks: t,
             phantom: PhantomData,
         }
     }
 
-    /*    pub fn from_seconds(seconds: T) -> Self {
-        // TODO check for overflow and generate error
-        Self::new(seconds * T::from(S::TICKS_PER_SECOND).unwrap())
-    }
-
-    pub fn from_milliseconds(milliseconds: T) -> Self {
-        // TODO check for overflow and generate error
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(1000).unwrap();
-        Self::new(milliseconds * factor)
-    }
-
-    pub fn from_microseconds(microseconds: T) -> Self {
-        // TODO check for overflow and generate error
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(1_000_000).unwrap();
-        Self::new(microseconds * factor)
-    }
-
-    pub fn from_nanoseconds(nanoseconds: T) -> Self {
-        // TODO check for overflow and generate error
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(1_000_000_000).unwrap();
-        Self::new(nanoseconds * factor)
-    }*/
-
     // Can't implement TryFrom trait because of the blanket specialization in core.
     // https://github.com/rust-lang/rust/issues/50133
     pub fn try_from<T2>(value: Instant<T2, S>) -> Result<Self, InstantOutOfRange>
@@ -108,37 +97,95 @@ impl<T: Tick, S: Scale> Duration<T, S> {
         Duration::new(self.ticks.widen())
     }
 
+    /// Converts the duration to a coarser scale, rounding towards negative infinity.
     pub fn floor<S2: Scale>(&self) -> Duration<T, S2> {
-        assert!(
-            S2::TICKS_PER_SECOND <= S::TICKS_PER_SECOND,
-            "Cannot floor to a higher scale"
-        );
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(S2::TICKS_PER_SECOND).unwrap();
-        Duration::new(self.ticks / factor)
+        self.round(Rounding::Floor)
     }
 
+    /// Converts the duration to a coarser scale, rounding according to the mode. Converting to
+    /// a finer scale fails to compile; use [Duration::extend] for that.
+    pub fn round<S2: Scale>(&self, mode: Rounding) -> Duration<T, S2> {
+        let () = ScaleConversion::<S, S2>::COARSER;
+        // The result is never larger in magnitude than the original, so it always fits.
+        Duration::new(ScaleConversion::<S, S2>::convert(self.ticks, mode).unwrap())
+    }
+
+    /// Rounds the duration to a multiple of another, e.g. to the nearest 15 minutes. Returns
+    /// None if the multiple isn't positive or the result can't be represented in T.
+    pub fn round_to_multiple(&self, multiple: Self, mode: Rounding) -> Option<Self> {
+        if multiple.ticks <= T::zero() {
+            return None;
+        }
+        let quotient = div_round_by(self.ticks, multiple.ticks, mode);
+        Some(Self::new(quotient.checked_mul(&multiple.ticks)?))
+    }
+
+    /// Converts the duration to a finer scale. If the ticks of this scale aren't a whole number
+    /// of ticks of S2, the result is rounded towards negative infinity. Returns None if the
+    /// result can't be represented in T.
     pub fn extend<S2: Scale>(&self) -> Option<Duration<T, S2>> {
-        assert!(
-            S2::TICKS_PER_SECOND >= S::TICKS_PER_SECOND,
-            "Cannot extend scale to a lower scale"
-        );
-        let factor = T::from(S2::TICKS_PER_SECOND).unwrap() / T::from(S::TICKS_PER_SECOND).unwrap();
-        Some(Duration::new(self.ticks.checked_mul(&factor)?))
+        let () = ScaleConversion::<S, S2>::FINER;
+        Some(Duration::new(ScaleConversion::<S, S2>::convert(
+            self.ticks,
+            Rounding::Floor,
+        )?))
+    }
+
+    /// Converts a [std::time::Duration] to a duration of the scale S, rounding towards zero if
+    /// it isn't a whole number of ticks. Returns None if it can't be represented in T.
+    pub fn from_std(duration: std::time::Duration) -> Option<Self> {
+        Self::from_std_signed(false, duration)
+    }
+
+    /// Like [Duration::from_std], but negates the duration if `negative` is true, since
+    /// [std::time::Duration] can't be negative itself.
+    pub fn from_std_signed(negative: bool, duration: std::time::Duration) -> Option<Self> {
+        // At most 2^64 seconds, which fits in an i128 of nanoseconds.
+        let nanoseconds = duration.as_nanos() as i128;
+        let nanoseconds = if negative { -nanoseconds } else { nanoseconds };
+        let ticks = ScaleConversion::<Nanoseconds, S>::convert(nanoseconds, Rounding::TowardZero)?;
+        Some(Duration::new(T::from(ticks)?))
+    }
+
+    /// Converts the duration to a [std::time::Duration], rounding towards zero to whole
+    /// nanoseconds. Returns None if the duration is negative or too long.
+    pub fn to_std(&self) -> Option<std::time::Duration> {
+        match self.to_std_signed()? {
+            (false, duration) => Some(duration),
+            (true, duration) if duration.is_zero() => Some(duration),
+            (true, _) => None,
+        }
+    }
+
+    /// Converts the absolute value of the duration to a [std::time::Duration], rounding towards
+    /// zero to whole nanoseconds, and returns it together with whether the duration is
+    /// negative. Returns None if the duration is too long.
+    pub fn to_std_signed(&self) -> Option<(bool, std::time::Duration)> {
+        let nanoseconds = ScaleConversion::<S, Nanoseconds>::convert(
+            self.ticks.to_i128()?,
+            Rounding::TowardZero,
+        )?;
+        let (seconds, nanosecond) = nanoseconds.unsigned_abs().div_rem(&1_000_000_000);
+        Some((
+            self.is_negative(),
+            std::time::Duration::new(u64::try_from(seconds).ok()?, nanosecond as u32),
+        ))
     }
 
     pub fn div_rem_floor<S2: Scale>(&self, other: Duration<T, S2>) -> (T, Duration<T, S>) {
-        // We have two numbers t1/s1 and t2/s2. We want to compute
+        // We have two numbers t1/s1 and t2/s2, where the ticks per second s1 and s2 may be
+        // fractions themselves. We want to compute
         //
         // (t1/s1) / (t2/s2) = (t1*s2) / (t2*s1)
         //
         // precisely, i.e. both a quotient and a remainder. The quotient will be returned as
-        // is, and the remainder will be scaled up to the scale of the Duration type.
-        let t1 = Ratio::new(self.ticks, T::from(S::TICKS_PER_SECOND).unwrap());
-        let t2 = Ratio::new(other.ticks, T::from(S2::TICKS_PER_SECOND).unwrap());
+        // is, and the remainder will be scaled up to the scale of the Duration type. If the
+        // ticks of S2 aren't whole ticks of S, the remainder is rounded down.
+        let (t1, t2) = self.seconds_ratios(other);
         let quotient = (t1 / t2).floor();
         let remainder = t1 - t2 * quotient;
         // Scale the remainder up to the scale parameter of the Duration type.
-        let remainder = remainder * T::from(S::TICKS_PER_SECOND).unwrap();
+        let remainder = (remainder * ticks_per_second::<T, S>()).floor();
         (quotient.to_integer(), Duration::new(remainder.to_integer()))
     }
 
@@ -149,32 +196,384 @@ impl<T: Tick, S: Scale> Duration<T, S> {
     pub fn div_rem_ceil<S2: Scale>(&self, other: Duration<T, S2>) -> (T, Duration<T, S>) {
         // This is the same as with div_rem_floor, but we use a ceiling operation
         // instead of a flooring operation to get the quotient.
-        let t1 = Ratio::new(self.ticks, T::from(S::TICKS_PER_SECOND).unwrap());
-        let t2 = Ratio::new(other.ticks, T::from(S2::TICKS_PER_SECOND).unwrap());
+        let (t1, t2) = self.seconds_ratios(other);
         let quotient = (t1 / t2).ceil();
         let remainder = t2 * quotient - t1;
-        let remainder = remainder * T::from(S::TICKS_PER_SECOND).unwrap();
+        let remainder = (remainder * ticks_per_second::<T, S>()).floor();
         (quotient.to_integer(), Duration::new(remainder.to_integer()))
     }
 
+    // Both durations as exact numbers of seconds.
+    fn seconds_ratios<S2: Scale>(&self, other: Duration<T, S2>) -> (Ratio<T>, Ratio<T>) {
+        let t1 = Ratio::from_integer(self.ticks) / ticks_per_second::<T, S>();
+        let t2 = Ratio::from_integer(other.ticks) / ticks_per_second::<T, S2>();
+        (t1, t2)
+    }
+
     pub(crate) fn ticks(&self) -> T {
         self.ticks
     }
+
+    /// The number of ticks of the scale S in this duration.
+    pub fn num_ticks(&self) -> T {
+        self.ticks
+    }
 }
 
-impl<T: Tick, S: Scale> Add for Duration<T, S> {
-    type Output = Self;
+// Constructors and accessors for each unit. A unit is given as a number of seconds per unit and
+// a number of units per second, one of which is always 1. A day is always 86,400 seconds here,
+// since a duration is elapsed time; use the calendar arithmetic of DateTime for calendar days
+// that may contain a leap second.
+macro_rules! duration_unit {
+    ($from:ident, $whole:ident, $as_f64:ident, $unit:literal, $seconds_per_unit:expr, $units_per_second:expr) => {
+        #[doc = concat!("Creates a duration of the given number of ", $unit, ". Returns None if ")]
+        #[doc = "the result doesn't fit in T, or isn't a whole number of ticks of the scale S."]
+        pub fn $from(value: T) -> Option<Self> {
+            let (multiplier, divisor) = unit_to_ticks::<S>($seconds_per_unit, $units_per_second);
+            Some(Self::new(mul_div_exact(value, multiplier, divisor)?))
+        }
+
+        #[doc = concat!("The number of whole ", $unit, " in this duration, truncated towards ")]
+        #[doc = "zero. Returns None if it doesn't fit in T."]
+        pub fn $whole(&self) -> Option<T> {
+            let (multiplier, divisor) = unit_to_ticks::<S>($seconds_per_unit, $units_per_second);
+            mul_div_round(self.ticks, divisor, multiplier, Rounding::TowardZero)
+        }
+
+        #[doc = concat!("The duration in ", $unit, ", as a floating point number.")]
+        pub fn $as_f64(&self) -> f64 {
+            let (multiplier, divisor) = unit_to_ticks::<S>($seconds_per_unit, $units_per_second);
+            self.ticks.to_f64().unwrap_or(f64::NAN) * divisor as f64 / multiplier as f64
+        }
+    };
+}
+
+impl<T: Tick, S: Scale> Duration<T, S> {
+    duration_unit!(from_days, whole_days, as_days_f64, "days", 86_400, 1);
+    duration_unit!(from_hours, whole_hours, as_hours_f64, "hours", 3_600, 1);
+    duration_unit!(
+        from_minutes,
+        whole_minutes,
+        as_minutes_f64,
+        "minutes",
+        60,
+        1
+    );
+    duration_unit!(from_seconds, whole_seconds, as_seconds_f64, "seconds", 1, 1);
+    duration_unit!(
+        from_millis,
+        whole_millis,
+        as_millis_f64,
+        "milliseconds",
+        1,
+        1_000
+    );
+    duration_unit!(
+        from_micros,
+        whole_micros,
+        as_micros_f64,
+        "microseconds",
+        1,
+        1_000_000
+    );
+    duration_unit!(
+        from_nanos,
+        whole_nanos,
+        as_nanos_f64,
+        "nanoseconds",
+        1,
+        1_000_000_000
+    );
+
+    /// The part of the duration that is less than a second, in whole milliseconds. It has the
+    /// same sign as the duration.
+    pub fn subsec_millis(&self) -> i32 {
+        self.subsec(1_000)
+    }
+
+    /// The part of the duration that is less than a second, in whole microseconds. It has the
+    /// same sign as the duration.
+    pub fn subsec_micros(&self) -> i32 {
+        self.subsec(1_000_000)
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.ticks + rhs.ticks)
+    /// The part of the duration that is less than a second, in whole nanoseconds. It has the
+    /// same sign as the duration.
+    pub fn subsec_nanos(&self) -> i32 {
+        self.subsec(1_000_000_000)
+    }
+
+    fn subsec(&self, units_per_second: u128) -> i32 {
+        // TICKS_PER_SECOND ticks are TICKS_PER_SECOND_DENOMINATOR whole seconds, so the remainder
+        // is less than TICKS_PER_SECOND_DENOMINATOR seconds, has the same part less than a second
+        // as the duration, and fits in an i128 whatever T is.
+        let remainder = match T::from(S::TICKS_PER_SECOND) {
+            Some(ticks_per_second) => self.ticks % ticks_per_second,
+            None => self.ticks,
+        };
+        let remainder = remainder.to_i128().unwrap();
+        // Less than units_per_second * TICKS_PER_SECOND_DENOMINATOR, which fits in an i128.
+        let units = mul_div_round(
+            remainder,
+            units_per_second * S::TICKS_PER_SECOND_DENOMINATOR as u128,
+            S::TICKS_PER_SECOND as u128,
+            Rounding::TowardZero,
+        )
+        .unwrap();
+        // Less than units_per_second, which fits in an i32.
+        (units % units_per_second as i128) as i32
     }
 }
 
-impl<T: Tick, S: Scale> Sub for Duration<T, S> {
-    type Output = Self;
+// The number of ticks per second of the scale S, as a fraction of T.
+fn ticks_per_second<T: Tick, S: Scale>() -> Ratio<T> {
+    let ticks_per_second = S::ticks_per_second();
+    Ratio::new(
+        T::from(*ticks_per_second.numer()).expect("ticks per second is too large for type T"),
+        T::from(*ticks_per_second.denom()).expect("ticks per second is too large for type T"),
+    )
+}
+
+// Returns the reduced fraction multiplier/divisor that converts a value in the given unit to
+// ticks of the scale S.
+fn unit_to_ticks<S: Scale>(seconds_per_unit: u128, units_per_second: u128) -> (u128, u128) {
+    let multiplier = seconds_per_unit * S::TICKS_PER_SECOND as u128;
+    let divisor = units_per_second * S::TICKS_PER_SECOND_DENOMINATOR as u128;
+    let gcd = multiplier.gcd(&divisor);
+    (multiplier / gcd, divisor / gcd)
+}
+
+impl<T: Tick, S: Scale> Duration<T, S> {
+    // The arithmetic methods below mirror the ones on the primitive integer types. Unlike the
+    // primitive types, the operators (+, -, * and so on) panic on overflow in release builds
+    // too, so use these when overflow is possible.
+
+    /// Adds a duration, which may have a different tick type and scale. The result has the
+    /// least common width of the two tick types and the finer of the two scales. Returns None
+    /// if the result can't be represented in that type.
+    pub fn checked_add<T2: Tick, S2: Scale>(
+        &self,
+        rhs: Duration<T2, S2>,
+    ) -> Option<CommonDuration<T, S, T2, S2>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        let (lhs, rhs) = self.common_ticks(rhs)?;
+        Some(Duration::new(CheckedAdd::checked_add(&lhs, &rhs)?))
+    }
+
+    /// Subtracts a duration, which may have a different tick type and scale. See
+    /// [Duration::checked_add] for the type of the result.
+    pub fn checked_sub<T2: Tick, S2: Scale>(
+        &self,
+        rhs: Duration<T2, S2>,
+    ) -> Option<CommonDuration<T, S, T2, S2>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        let (lhs, rhs) = self.common_ticks(rhs)?;
+        Some(Duration::new(CheckedSub::checked_sub(&lhs, &rhs)?))
+    }
+
+    pub fn checked_mul(&self, rhs: T) -> Option<Self> {
+        Some(Self::new(self.ticks.checked_mul(&rhs)?))
+    }
+
+    /// Divides the duration by an integer, truncating towards zero. Returns None if `rhs` is
+    /// zero or the division overflows.
+    pub fn checked_div(&self, rhs: T) -> Option<Self> {
+        Some(Self::new(self.ticks.checked_div(&rhs)?))
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.ticks - rhs.ticks)
+    /// Returns the remainder of dividing this duration by another, with the same sign as this
+    /// duration. Returns None if `rhs` is zero or the division overflows.
+    pub fn checked_rem(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(self.ticks.checked_rem(&rhs.ticks)?))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(self.ticks.checked_neg()?))
+    }
+
+    pub fn checked_abs(&self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(*self)
+        }
+    }
+
+    pub fn saturating_add(&self, rhs: Self) -> Self {
+        Self::new(self.ticks.saturating_add(rhs.ticks))
+    }
+
+    pub fn saturating_sub(&self, rhs: Self) -> Self {
+        Self::new(self.ticks.saturating_sub(rhs.ticks))
+    }
+
+    pub fn saturating_mul(&self, rhs: T) -> Self {
+        Self::new(SaturatingMul::saturating_mul(&self.ticks, &rhs))
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn saturating_div(&self, rhs: T) -> Self {
+        let (ticks, overflow) = self.overflowing_div(rhs);
+        if overflow {
+            Self::max_value()
+        } else {
+            ticks
+        }
+    }
+
+    pub fn saturating_neg(&self) -> Self {
+        self.checked_neg().unwrap_or_else(|| {
+            if self.is_negative() {
+                Self::max_value()
+            } else {
+                Self::min_value()
+            }
+        })
+    }
+
+    pub fn saturating_abs(&self) -> Self {
+        self.checked_abs().unwrap_or_else(Self::max_value)
+    }
+
+    pub fn wrapping_add(&self, rhs: Self) -> Self {
+        Self::new(self.ticks.wrapping_add(&rhs.ticks))
+    }
+
+    pub fn wrapping_sub(&self, rhs: Self) -> Self {
+        Self::new(self.ticks.wrapping_sub(&rhs.ticks))
+    }
+
+    pub fn wrapping_mul(&self, rhs: T) -> Self {
+        Self::new(self.ticks.wrapping_mul(&rhs))
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn wrapping_div(&self, rhs: T) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn wrapping_rem(&self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    pub fn wrapping_neg(&self) -> Self {
+        Self::new(self.ticks.wrapping_neg())
+    }
+
+    pub fn wrapping_abs(&self) -> Self {
+        if self.is_negative() {
+            self.wrapping_neg()
+        } else {
+            *self
+        }
+    }
+
+    pub fn overflowing_add(&self, rhs: Self) -> (Self, bool) {
+        let (ticks, overflow) = self.ticks.overflowing_add(&rhs.ticks);
+        (Self::new(ticks), overflow)
+    }
+
+    pub fn overflowing_sub(&self, rhs: Self) -> (Self, bool) {
+        let (ticks, overflow) = self.ticks.overflowing_sub(&rhs.ticks);
+        (Self::new(ticks), overflow)
+    }
+
+    pub fn overflowing_mul(&self, rhs: T) -> (Self, bool) {
+        let (ticks, overflow) = self.ticks.overflowing_mul(&rhs);
+        (Self::new(ticks), overflow)
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn overflowing_div(&self, rhs: T) -> (Self, bool) {
+        assert!(!rhs.is_zero(), "duration division by zero");
+        match self.checked_div(rhs) {
+            Some(quotient) => (quotient, false),
+            // The only overflowing division is T::MIN / -1, which wraps to T::MIN.
+            None => (*self, true),
+        }
+    }
+
+    /// Panics if `rhs` is zero.
+    pub fn overflowing_rem(&self, rhs: Self) -> (Self, bool) {
+        assert!(!rhs.ticks.is_zero(), "duration remainder by zero");
+        match self.checked_rem(rhs) {
+            Some(remainder) => (remainder, false),
+            // As with division, only T::MIN % -1 overflows, and the remainder is zero.
+            None => (Self::new(T::zero()), true),
+        }
+    }
+
+    pub fn overflowing_neg(&self) -> (Self, bool) {
+        (self.wrapping_neg(), self.checked_neg().is_none())
+    }
+
+    pub fn overflowing_abs(&self) -> (Self, bool) {
+        if self.is_negative() {
+            self.overflowing_neg()
+        } else {
+            (*self, false)
+        }
+    }
+
+    /// Returns the ticks of this duration and another, converted to their least common width
+    /// and the finer of the two scales. Returns None if either doesn't fit.
+    pub(crate) fn common_ticks<T2: Tick, S2: Scale>(
+        self,
+        other: Duration<T2, S2>,
+    ) -> Option<(CommonTick<T, T2>, CommonTick<T, T2>)>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        let (lhs, rhs) = self.ticks.least_common_width(other.ticks);
+        Some((
+            ScaleConversion::<S, CommonScale<S, S2>>::convert_exact(lhs)?,
+            ScaleConversion::<S2, CommonScale<S, S2>>::convert_exact(rhs)?,
+        ))
+    }
+
+    fn is_negative(&self) -> bool {
+        self.ticks < T::zero()
+    }
+
+    fn min_value() -> Self {
+        Self::new(T::min_value())
+    }
+
+    fn max_value() -> Self {
+        Self::new(T::max_value())
+    }
+}
+
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> Add<Duration<T2, S2>> for Duration<T1, S1>
+where
+    T1: LeastCommonWidth<T2>,
+    S1: FinerScale<S2>,
+{
+    type Output = CommonDuration<T1, S1, T2, S2>;
+
+    fn add(self, rhs: Duration<T2, S2>) -> Self::Output {
+        self.checked_add(rhs).expect("duration addition overflow")
+    }
+}
+
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> Sub<Duration<T2, S2>> for Duration<T1, S1>
+where
+    T1: LeastCommonWidth<T2>,
+    S1: FinerScale<S2>,
+{
+    type Output = CommonDuration<T1, S1, T2, S2>;
+
+    fn sub(self, rhs: Duration<T2, S2>) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("duration subtraction overflow")
     }
 }
 
@@ -182,6 +581,313 @@ impl<T: Tick, S: Scale> Mul<T> for Duration<T, S> {
     type Output = Self;
 
     fn mul(self, rhs: T) -> Self::Output {
-        Self::new(self.ticks * rhs)
+        self.checked_mul(rhs)
+            .expect("duration multiplication overflow")
+    }
+}
+
+impl<T: Tick, S: Scale> Div<T> for Duration<T, S> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        let (quotient, overflow) = self.overflowing_div(rhs);
+        assert!(!overflow, "duration division overflow");
+        quotient
+    }
+}
+
+impl<T: Tick, S: Scale> Rem for Duration<T, S> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let (remainder, overflow) = self.overflowing_rem(rhs);
+        assert!(!overflow, "duration remainder overflow");
+        remainder
+    }
+}
+
+impl<T: Tick, S: Scale> Neg for Duration<T, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("duration negation overflow")
+    }
+}
+
+impl<T: Tick, S: Scale> AddAssign for Duration<T, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.checked_add(rhs).expect("duration addition overflow");
+    }
+}
+
+impl<T: Tick, S: Scale> SubAssign for Duration<T, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self
+            .checked_sub(rhs)
+            .expect("duration subtraction overflow");
+    }
+}
+
+impl<T: Tick, S: Scale> TryFrom<std::time::Duration> for Duration<T, S> {
+    type Error = DurationOutOfRange;
+
+    fn try_from(value: std::time::Duration) -> Result<Self, Self::Error> {
+        Duration::from_std(value).ok_or(DurationOutOfRange)
+    }
+}
+
+impl<T: Tick, S: Scale> TryFrom<Duration<T, S>> for std::time::Duration {
+    type Error = DurationOutOfRange;
+
+    fn try_from(value: Duration<T, S>) -> Result<Self, Self::Error> {
+        value.to_std().ok_or(DurationOutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scale::{Attoseconds, Picoseconds, Timebase};
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = DurationS64::new(i64::MAX);
+        let one = DurationS64::new(1);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(max.checked_sub(one), Some(DurationS64::new(i64::MAX - 1)));
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(DurationS64::new(i64::MIN).checked_div(-1), None);
+        assert_eq!(
+            DurationS64::new(-7).checked_rem(DurationS64::new(3)),
+            Some(DurationS64::new(-1))
+        );
+        assert_eq!(DurationS64::new(i64::MIN).checked_abs(), None);
+        assert_eq!(Duration::<u32, Seconds>::new(1).checked_neg(), None);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let min = DurationS64::new(i64::MIN);
+        assert_eq!(min.saturating_sub(DurationS64::new(1)), min);
+        assert_eq!(min.saturating_mul(2), min);
+        assert_eq!(min.saturating_mul(-2), DurationS64::new(i64::MAX));
+        assert_eq!(min.saturating_div(-1), DurationS64::new(i64::MAX));
+        assert_eq!(min.saturating_neg(), DurationS64::new(i64::MAX));
+        assert_eq!(min.saturating_abs(), DurationS64::new(i64::MAX));
+        assert_eq!(Duration::<u32, Seconds>::new(5).saturating_neg().ticks(), 0);
+    }
+
+    #[test]
+    fn wrapping_and_overflowing_arithmetic() {
+        let min = DurationS64::new(i64::MIN);
+        let max = DurationS64::new(i64::MAX);
+        assert_eq!(max.wrapping_add(DurationS64::new(1)), min);
+        assert_eq!(max.overflowing_add(DurationS64::new(1)), (min, true));
+        assert_eq!(min.overflowing_div(-1), (min, true));
+        assert_eq!(min.wrapping_rem(DurationS64::new(-1)), DurationS64::new(0));
+        assert_eq!(min.overflowing_neg(), (min, true));
+        assert_eq!(min.wrapping_abs(), min);
+        assert_eq!(
+            DurationS64::new(-3).overflowing_abs(),
+            (DurationS64::new(3), false)
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let mut d = DurationS64::new(10);
+        d += DurationS64::new(5);
+        d -= DurationS64::new(3);
+        assert_eq!(d, DurationS64::new(12));
+        assert_eq!(d / 5, DurationS64::new(2));
+        assert_eq!(d % DurationS64::new(5), DurationS64::new(2));
+        assert_eq!(-d, DurationS64::new(-12));
+    }
+
+    #[test]
+    fn mixed_operators() {
+        let seconds = Duration::<i32, Seconds>::new(2);
+        let milliseconds = Duration::<u8, Milliseconds>::new(250);
+        let sum: Duration<i32, Milliseconds> = seconds + milliseconds;
+        assert_eq!(sum.ticks(), 2_250);
+        let difference: Duration<i32, Milliseconds> = milliseconds - seconds;
+        assert_eq!(difference.ticks(), -1_750);
+        assert_eq!(
+            Duration::<u8, Seconds>::new(1).checked_add(Duration::<u8, Milliseconds>::new(0)),
+            None
+        );
+    }
+
+    #[test]
+    fn unit_constructors() {
+        assert_eq!(DurationS64::from_days(2), Some(DurationS64::new(172_800)));
+        assert_eq!(DurationS64::from_millis(3_000), Some(DurationS64::new(3)));
+        assert_eq!(DurationS64::from_millis(3_500), None);
+        assert_eq!(
+            DurationNs128::from_hours(1).unwrap().num_ticks(),
+            3_600_000_000_000
+        );
+        assert_eq!(DurationNs128::from_micros(-7).unwrap().num_ticks(), -7_000);
+        assert_eq!(Duration::<i32, Nanoseconds>::from_seconds(3), None);
+        assert_eq!(
+            Duration::<i8, Nanoseconds>::from_nanos(0)
+                .unwrap()
+                .num_ticks(),
+            0
+        );
+        assert_eq!(
+            Duration::<u16, Milliseconds>::from_minutes(1)
+                .unwrap()
+                .num_ticks(),
+            60_000
+        );
+    }
+
+    #[test]
+    fn unit_accessors() {
+        let duration = DurationNs128::new(-90_061_234_567_891);
+        assert_eq!(duration.whole_days(), Some(-1));
+        assert_eq!(duration.whole_hours(), Some(-25));
+        assert_eq!(duration.whole_minutes(), Some(-1_501));
+        assert_eq!(duration.whole_seconds(), Some(-90_061));
+        assert_eq!(duration.whole_millis(), Some(-90_061_234));
+        assert_eq!(duration.subsec_millis(), -234);
+        assert_eq!(duration.subsec_micros(), -234_567);
+        assert_eq!(duration.subsec_nanos(), -234_567_891);
+        assert_eq!(duration.as_seconds_f64(), -90_061.234_567_891);
+
+        let seconds = Duration::<i32, Seconds>::new(i32::MAX);
+        assert_eq!(seconds.whole_millis(), None);
+        assert_eq!(seconds.whole_hours(), Some(596_523));
+        assert_eq!(seconds.subsec_nanos(), 0);
+        assert_eq!(
+            Duration::<i32, Milliseconds>::new(1_500).as_minutes_f64(),
+            0.025
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let duration = DurationNs128::new(-1_500_000_000);
+        assert_eq!(duration.floor::<Seconds>().num_ticks(), -2);
+        assert_eq!(
+            duration.round::<Seconds>(Rounding::TowardZero).num_ticks(),
+            -1
+        );
+        assert_eq!(
+            duration.round::<Seconds>(Rounding::HalfEven).num_ticks(),
+            -2
+        );
+        assert_eq!(
+            duration.round::<Milliseconds>(Rounding::Ceil).num_ticks(),
+            -1_500
+        );
+
+        let quarter = DurationS64::from_minutes(15).unwrap();
+        let duration = DurationS64::from_minutes(52).unwrap();
+        let rounded = duration.round_to_multiple(quarter, Rounding::HalfUp);
+        assert_eq!(rounded, DurationS64::from_minutes(45));
+        let rounded = duration.round_to_multiple(quarter, Rounding::Ceil);
+        assert_eq!(rounded, DurationS64::from_minutes(60));
+        assert_eq!(
+            duration.round_to_multiple(DurationS64::new(0), Rounding::Floor),
+            None
+        );
+        let max = DurationS64::new(i64::MAX);
+        assert_eq!(max.round_to_multiple(quarter, Rounding::Ceil), None);
+    }
+
+    #[test]
+    fn rational_scales() {
+        type Ntsc = Timebase<30_000, 1_001>;
+        let frame = Duration::<i64, Ntsc>::new(1);
+        let (frames, remainder) =
+            Duration::<i64, Nanoseconds>::new(10_000_000_000).div_rem_floor(frame);
+        assert_eq!(frames, 299);
+        // 10 s - 299 * 1001/30000 s = 701/30000 s
+        assert_eq!(remainder, Duration::new(23_366_666));
+        let (frames, remainder) =
+            Duration::<i64, Ntsc>::new(30_001).div_rem_floor(DurationS64::new(7));
+        assert_eq!(frames, 143);
+        assert_eq!(remainder, Duration::new(1));
+        let (frames, remainder) =
+            Duration::<i64, Nanoseconds>::new(10_000_000_000).div_rem_ceil(frame);
+        assert_eq!(frames, 300);
+        assert_eq!(remainder, Duration::new(10_000_000));
+
+        assert_eq!(Duration::<i64, Ntsc>::new(30).subsec_nanos(), 1_000_000);
+        assert_eq!(Duration::<i64, Ntsc>::new(-31).subsec_nanos(), -34_366_666);
+        assert_eq!(
+            Duration::<i64, Ntsc>::new(30_000).whole_seconds(),
+            Some(1_001)
+        );
+        assert_eq!(
+            Duration::<i64, Ntsc>::new(30_001).whole_seconds(),
+            Some(1_001)
+        );
+        assert_eq!(
+            Duration::<i64, Ntsc>::from_seconds(1_001),
+            Some(Duration::new(30_000))
+        );
+        assert_eq!(Duration::<i64, Ntsc>::from_seconds(1), None);
+        assert_eq!(
+            Duration::<i64, Timebase<48_000, 1>>::from_millis(10),
+            Some(Duration::new(480))
+        );
+    }
+
+    #[test]
+    fn std_duration() {
+        let std_duration = std::time::Duration::from_millis(1_500);
+        type DurationMs64 = Duration<i64, Milliseconds>;
+        assert_eq!(
+            DurationMs64::from_std(std_duration),
+            Some(Duration::new(1_500))
+        );
+        assert_eq!(
+            DurationMs64::from_std_signed(true, std_duration),
+            Some(Duration::new(-1_500))
+        );
+        assert_eq!(DurationS64::from_std(std_duration), Some(Duration::new(1)));
+        assert_eq!(
+            DurationS64::from_std_signed(true, std_duration),
+            Some(Duration::new(-1))
+        );
+        assert_eq!(Duration::<u8, Milliseconds>::from_std(std_duration), None);
+        assert_eq!(
+            Duration::<i128, Attoseconds>::from_std(std::time::Duration::MAX),
+            Some(Duration::new(
+                u64::MAX as i128 * 1_000_000_000_000_000_000 + 999_999_999_000_000_000
+            ))
+        );
+        let converted: DurationMs64 = std_duration.try_into().unwrap();
+        assert_eq!(converted, Duration::new(1_500));
+
+        assert_eq!(DurationMs64::new(1_500).to_std(), Some(std_duration));
+        assert_eq!(DurationMs64::new(-1_500).to_std(), None);
+        assert_eq!(
+            DurationMs64::new(-1_500).to_std_signed(),
+            Some((true, std_duration))
+        );
+        assert_eq!(
+            Duration::<i64, Picoseconds>::new(-1).to_std(),
+            Some(std::time::Duration::ZERO)
+        );
+        assert_eq!(
+            Duration::<i128, Seconds>::new(u64::MAX as i128 + 1).to_std(),
+            None
+        );
+        assert_eq!(
+            std::time::Duration::try_from(DurationNs128::new(1_500_000_000)).ok(),
+            Some(std_duration)
+        );
+        assert!(std::time::Duration::try_from(DurationNs128::new(-1)).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "duration addition overflow")]
+    fn add_overflow_panics() {
+        let _ = DurationS64::new(i64::MAX) + DurationS64::new(1);
     }
 }
@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+
+use num_rational::Ratio;
+
+use crate::instant::Tick;
+use crate::scale::Conversion;
+use crate::{Duration, Scale};
+
+/// A duration whose scale is only known at runtime. It is to [Duration] what
+/// [DynInstant](crate::DynInstant) is to [Instant](crate::Instant), and compares by the time it
+/// represents in the same way.
+#[derive(Debug, Clone, Copy)]
+pub struct DynDuration<T: Tick> {
+    ticks: T,
+    ticks_per_second: Ratio<u64>,
+}
+
+impl<T: Tick> DynDuration<T> {
+    /// Creates a duration of the given number of ticks, where the ticks per second may be a
+    /// whole number or a fraction. Panics if the ticks per second are zero.
+    pub fn new<R: Into<Ratio<u64>>>(ticks: T, ticks_per_second: R) -> Self {
+        let ticks_per_second = ticks_per_second.into();
+        assert!(
+            *ticks_per_second.numer() != 0,
+            "ticks per second must not be zero"
+        );
+        DynDuration {
+            ticks,
+            ticks_per_second,
+        }
+    }
+
+    pub fn num_ticks(&self) -> T {
+        self.ticks
+    }
+
+    /// The number of ticks per second, as a fraction in lowest terms.
+    pub fn ticks_per_second(&self) -> Ratio<u64> {
+        self.ticks_per_second
+    }
+
+    /// Converts the duration to the static scale S. Returns None if it isn't a whole number of
+    /// ticks of S, or can't be represented in T.
+    pub fn to_duration<S: Scale>(&self) -> Option<Duration<T, S>> {
+        let ticks = self
+            .conversion_to(S::ticks_per_second())
+            .convert_exact(self.ticks)?;
+        Some(Duration::new(ticks))
+    }
+
+    fn conversion_to(&self, ticks_per_second: Ratio<u64>) -> Conversion {
+        Conversion::between(self.ticks_per_second, ticks_per_second)
+    }
+}
+
+impl<T: Tick, S: Scale> From<Duration<T, S>> for DynDuration<T> {
+    fn from(value: Duration<T, S>) -> Self {
+        DynDuration::new(value.num_ticks(), S::ticks_per_second())
+    }
+}
+
+impl<T1: Tick, T2: Tick> PartialEq<DynDuration<T2>> for DynDuration<T1> {
+    fn eq(&self, other: &DynDuration<T2>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<T: Tick> Eq for DynDuration<T> {}
+
+impl<T1: Tick, T2: Tick> PartialOrd<DynDuration<T2>> for DynDuration<T1> {
+    fn partial_cmp(&self, other: &DynDuration<T2>) -> Option<Ordering> {
+        Some(
+            self.conversion_to(other.ticks_per_second)
+                .cmp(self.ticks, other.ticks),
+        )
+    }
+}
+
+impl<T: Tick> Ord for DynDuration<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.conversion_to(other.ticks_per_second)
+            .cmp(self.ticks, other.ticks)
+    }
+}
+
+impl<T1: Tick, T2: Tick, S: Scale> PartialEq<Duration<T2, S>> for DynDuration<T1> {
+    fn eq(&self, other: &Duration<T2, S>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<T1: Tick, T2: Tick, S: Scale> PartialOrd<Duration<T2, S>> for DynDuration<T1> {
+    fn partial_cmp(&self, other: &Duration<T2, S>) -> Option<Ordering> {
+        Some(
+            self.conversion_to(S::ticks_per_second())
+                .cmp(self.ticks, other.num_ticks()),
+        )
+    }
+}
+
+impl<T1: Tick, S: Scale, T2: Tick> PartialEq<DynDuration<T2>> for Duration<T1, S> {
+    fn eq(&self, other: &DynDuration<T2>) -> bool {
+        other == self
+    }
+}
+
+impl<T1: Tick, S: Scale, T2: Tick> PartialOrd<DynDuration<T2>> for Duration<T1, S> {
+    fn partial_cmp(&self, other: &DynDuration<T2>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scale::{Microseconds, Milliseconds, Nanoseconds};
+    use crate::{DurationNs128, DynInstant, Instant};
+
+    #[test]
+    fn conversion() {
+        let duration = DynDuration::from(Duration::<i64, Milliseconds>::new(-2_500));
+        assert_eq!(duration.num_ticks(), -2_500);
+        assert_eq!(duration.ticks_per_second(), Ratio::from_integer(1_000));
+        assert_eq!(
+            duration.to_duration::<Microseconds>(),
+            Some(Duration::new(-2_500_000))
+        );
+        assert_eq!(
+            DynDuration::new(3i64, Ratio::new(90_000, 1)).to_duration::<Nanoseconds>(),
+            None
+        );
+        assert_eq!(
+            DynDuration::new(9i64, 90_000u64).to_duration::<Nanoseconds>(),
+            Some(Duration::new(100_000))
+        );
+
+        let instant = DynInstant::from_ticks_since_epoch(7i64, 1_000u64);
+        assert_eq!(
+            instant.duration_since_epoch(),
+            DynDuration::new(7i64, 1_000u64)
+        );
+        assert_eq!(
+            instant.to_instant::<Milliseconds>(),
+            Some(Instant::from_ticks_since_epoch(7))
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        let micros = DynDuration::new(1_000i64, 1_000_000u64);
+        assert_eq!(micros, DynDuration::new(1i8, 1_000u64));
+        assert_eq!(micros, DurationNs128::new(1_000_000));
+        assert_eq!(DurationNs128::new(1_000_000), micros);
+        assert!(micros < DurationNs128::new(1_000_001));
+        assert!(DurationNs128::new(999_999) < micros);
+        assert!(micros > DynDuration::new(-1i64, 1u64));
+    }
+}
@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+
+use num_rational::Ratio;
+
+use crate::dyn_duration::DynDuration;
+use crate::instant::Tick;
+use crate::scale::Conversion;
+use crate::{Instant, Scale};
+
+/// An instant whose scale is only known at runtime, e.g. from the header of a file. It counts
+/// ticks since the epoch like [Instant], but carries its number of ticks per second with it.
+///
+/// Instants of different scales compare by the time they represent, the same as instants of
+/// different static scales do, so 1 s is equal to 1,000 ms.
+#[derive(Debug, Clone, Copy)]
+pub struct DynInstant<T: Tick> {
+    ticks: T,
+    ticks_per_second: Ratio<u64>,
+}
+
+impl<T: Tick> DynInstant<T> {
+    /// Creates an instant with the given number of ticks since the epoch, where the ticks per
+    /// second may be a whole number or a fraction. Panics if the ticks per second are zero.
+    pub fn from_ticks_since_epoch<R: Into<Ratio<u64>>>(ticks: T, ticks_per_second: R) -> Self {
+        let ticks_per_second = ticks_per_second.into();
+        assert!(
+            *ticks_per_second.numer() != 0,
+            "ticks per second must not be zero"
+        );
+        DynInstant {
+            ticks,
+            ticks_per_second,
+        }
+    }
+
+    pub fn ticks_since_epoch(&self) -> T {
+        self.ticks
+    }
+
+    /// The number of ticks per second, as a fraction in lowest terms.
+    pub fn ticks_per_second(&self) -> Ratio<u64> {
+        self.ticks_per_second
+    }
+
+    pub fn duration_since_epoch(&self) -> DynDuration<T> {
+        DynDuration::new(self.ticks, self.ticks_per_second)
+    }
+
+    /// Converts the instant to the static scale S. Returns None if it falls between two ticks of
+    /// S, or can't be represented in T.
+    pub fn to_instant<S: Scale>(&self) -> Option<Instant<T, S>> {
+        let ticks = self
+            .conversion_to(S::ticks_per_second())
+            .convert_exact(self.ticks)?;
+        Some(Instant::from_ticks_since_epoch(ticks))
+    }
+
+    fn conversion_to(&self, ticks_per_second: Ratio<u64>) -> Conversion {
+        Conversion::between(self.ticks_per_second, ticks_per_second)
+    }
+}
+
+impl<T: Tick, S: Scale> From<Instant<T, S>> for DynInstant<T> {
+    fn from(value: Instant<T, S>) -> Self {
+        DynInstant::from_ticks_since_epoch(value.ticks_since_epoch(), S::ticks_per_second())
+    }
+}
+
+impl<T1: Tick, T2: Tick> PartialEq<DynInstant<T2>> for DynInstant<T1> {
+    fn eq(&self, other: &DynInstant<T2>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<T: Tick> Eq for DynInstant<T> {}
+
+impl<T1: Tick, T2: Tick> PartialOrd<DynInstant<T2>> for DynInstant<T1> {
+    fn partial_cmp(&self, other: &DynInstant<T2>) -> Option<Ordering> {
+        Some(
+            self.conversion_to(other.ticks_per_second)
+                .cmp(self.ticks, other.ticks),
+        )
+    }
+}
+
+impl<T: Tick> Ord for DynInstant<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.conversion_to(other.ticks_per_second)
+            .cmp(self.ticks, other.ticks)
+    }
+}
+
+impl<T1: Tick, T2: Tick, S: Scale> PartialEq<Instant<T2, S>> for DynInstant<T1> {
+    fn eq(&self, other: &Instant<T2, S>) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<T1: Tick, T2: Tick, S: Scale> PartialOrd<Instant<T2, S>> for DynInstant<T1> {
+    fn partial_cmp(&self, other: &Instant<T2, S>) -> Option<Ordering> {
+        Some(
+            self.conversion_to(S::ticks_per_second())
+                .cmp(self.ticks, other.ticks_since_epoch()),
+        )
+    }
+}
+
+impl<T1: Tick, S: Scale, T2: Tick> PartialEq<DynInstant<T2>> for Instant<T1, S> {
+    fn eq(&self, other: &DynInstant<T2>) -> bool {
+        other == self
+    }
+}
+
+impl<T1: Tick, S: Scale, T2: Tick> PartialOrd<DynInstant<T2>> for Instant<T1, S> {
+    fn partial_cmp(&self, other: &DynInstant<T2>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scale::{Microseconds, Milliseconds, Nanoseconds, Seconds, Timebase};
+
+    #[test]
+    fn conversion() {
+        let micros = Instant::<i64, Microseconds>::from_ticks_since_epoch(1_500_000);
+        let instant = DynInstant::from(micros);
+        assert_eq!(instant.ticks_since_epoch(), 1_500_000);
+        assert_eq!(instant.ticks_per_second(), Ratio::from_integer(1_000_000));
+        assert_eq!(instant.to_instant::<Microseconds>(), Some(micros));
+        assert_eq!(
+            instant.to_instant::<Nanoseconds>(),
+            Some(Instant::from_ticks_since_epoch(1_500_000_000))
+        );
+        assert_eq!(
+            instant.to_instant::<Milliseconds>(),
+            Some(Instant::from_ticks_since_epoch(1_500))
+        );
+        assert_eq!(instant.to_instant::<Seconds>(), None);
+        assert_eq!(
+            DynInstant::from_ticks_since_epoch(i64::MAX, 1u64).to_instant::<Milliseconds>(),
+            None
+        );
+
+        let frames = DynInstant::from_ticks_since_epoch(30_000i64, Ratio::new(30_000, 1_001));
+        assert_eq!(
+            frames.to_instant::<Timebase<30_000, 1_001>>(),
+            Some(Instant::from_ticks_since_epoch(30_000))
+        );
+        assert_eq!(
+            frames.to_instant::<Seconds>(),
+            Some(Instant::from_ticks_since_epoch(1_001))
+        );
+    }
+
+    #[test]
+    fn comparison() {
+        let second = DynInstant::from_ticks_since_epoch(1i64, 1u64);
+        let millis = DynInstant::from_ticks_since_epoch(1_000i32, 1_000u64);
+        assert_eq!(second, millis);
+        assert!(DynInstant::from_ticks_since_epoch(1_001i64, 1_000u64) > second);
+        assert!(DynInstant::from_ticks_since_epoch(-1i8, 10u64) < millis);
+        assert_eq!(
+            second.cmp(&DynInstant::from_ticks_since_epoch(999, 1_000u64)),
+            Ordering::Greater
+        );
+
+        let nanos = Instant::<i128, Nanoseconds>::from_ticks_since_epoch(1_000_000_001);
+        assert!(second < nanos);
+        assert!(nanos > second);
+        assert_ne!(nanos, second);
+        assert_eq!(Instant::<i64, Seconds>::from_ticks_since_epoch(1), millis);
+    }
+
+    #[test]
+    #[should_panic(expected = "ticks per second must not be zero")]
+    fn zero_ticks_per_second() {
+        DynInstant::from_ticks_since_epoch(1i64, 0u64);
+    }
+}
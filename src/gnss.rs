@@ -0,0 +1,286 @@
+// GNSS receivers report time as a week number and a time of week, counted from the epoch of
+// the system's own time scale. GPS time, Galileo System Time (GST) and BeiDou Time (BDT) are
+// all continuous time scales without leap seconds, which makes them a natural fit for Instant:
+// an Instant counts every elapsed second since the Unix epoch, including leap seconds, so the
+// epoch of each system is simply a fixed offset from the Instant epoch.
+//
+// The offsets below are the number of seconds from the Instant epoch (1970-01-01 00:00:00 UTC)
+// to the start of week 0 of each system, i.e. the Unix timestamp of the epoch plus the number of
+// leap seconds that had been inserted into UTC by then.
+//
+// - GPS: 1980-01-06 00:00:00 UTC. Unix timestamp 315964800, 9 leap seconds.
+// - Galileo: 1999-08-22 00:00:00 GST, which is the start of GPS week 1024 (GST is steered to
+//   GPS time, so the week boundaries coincide).
+// - BeiDou: 2006-01-01 00:00:00 UTC. Unix timestamp 1136073600, 23 leap seconds.
+
+use num_traits::ToPrimitive;
+
+use crate::instant::Tick;
+use crate::iso8601::{Chronology, DateTime};
+use crate::scale::{ScaleConversion, Seconds};
+use crate::{Duration, Instant, Scale};
+
+const SECONDS_PER_WEEK: u32 = 604_800;
+
+const GPS_EPOCH_SECONDS: i64 = 315_964_809;
+const GALILEO_EPOCH_SECONDS: i64 = GPS_EPOCH_SECONDS + 1024 * SECONDS_PER_WEEK as i64;
+const BEIDOU_EPOCH_SECONDS: i64 = 1_136_073_623;
+
+/// A global navigation satellite system whose time scale is counted in weeks.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum GnssSystem {
+    Gps,
+    Galileo,
+    BeiDou,
+}
+
+impl GnssSystem {
+    /// Number of bits used for the week number in the system's broadcast navigation message.
+    /// The legacy GPS navigation message only has 10 bits, so the week number rolls over every
+    /// 1024 weeks (about 19.6 years).
+    pub fn broadcast_week_bits(&self) -> u32 {
+        match self {
+            GnssSystem::Gps => 10,
+            GnssSystem::Galileo => 12,
+            GnssSystem::BeiDou => 13,
+        }
+    }
+
+    fn epoch_seconds(&self) -> i64 {
+        match self {
+            GnssSystem::Gps => GPS_EPOCH_SECONDS,
+            GnssSystem::Galileo => GALILEO_EPOCH_SECONDS,
+            GnssSystem::BeiDou => BEIDOU_EPOCH_SECONDS,
+        }
+    }
+
+    /// Returns the instant at which week 0 of the system starts. Returns None if it can't be
+    /// represented in T, or falls between two ticks of a scale with a fractional number of ticks
+    /// per second.
+    pub fn epoch<T: Tick, S: Scale>(&self) -> Option<Instant<T, S>> {
+        let seconds = T::from(self.epoch_seconds())?;
+        let ticks = ScaleConversion::<Seconds, S>::convert_exact(seconds)?;
+        Some(Instant::from_ticks_since_epoch(ticks))
+    }
+}
+
+/// A point in time expressed as a full (non-truncated) week number and a time of week, as
+/// counted by a GNSS time scale.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GnssWeekTime<T: Tick, S: Scale> {
+    system: GnssSystem,
+    week: u32,
+    time_of_week: Duration<T, S>,
+}
+
+impl<T: Tick, S: Scale> GnssWeekTime<T, S> {
+    /// Create a week time from a full week number and a time of week. Returns None if the
+    /// time of week is negative or not shorter than one week.
+    pub fn new(system: GnssSystem, week: u32, time_of_week: Duration<T, S>) -> Option<Self> {
+        let tow = time_of_week.ticks();
+        if tow < T::zero() || tow >= week_ticks::<T, S>()? {
+            return None;
+        }
+        Some(GnssWeekTime {
+            system,
+            week,
+            time_of_week,
+        })
+    }
+
+    /// Create a week time from a week number that has been truncated to `week_bits` bits, as
+    /// broadcast by the satellites. The rollover ambiguity is resolved by picking the week
+    /// that is closest to the given reference instant, so the reference needs to be within
+    /// half a rollover period (e.g. about 9.8 years for 10-bit GPS weeks) of the actual time.
+    /// Returns None if `week_bits` isn't between 1 and 31, or if the truncated week doesn't fit
+    /// in that many bits.
+    pub fn resolve_rollover(
+        system: GnssSystem,
+        truncated_week: u32,
+        week_bits: u32,
+        time_of_week: Duration<T, S>,
+        reference: Instant<T, S>,
+    ) -> Option<Self> {
+        if !(1..32).contains(&week_bits) {
+            return None;
+        }
+        let period = 1_i64 << week_bits;
+        let truncated_week = truncated_week as i64;
+        if truncated_week >= period {
+            return None;
+        }
+
+        // Whole weeks of the reference, counted from the system epoch. The reference may be
+        // before the epoch, so we can't use GnssWeekTime::from_instant here.
+        let epoch = system.epoch::<T, S>()?;
+        let reference_offset = reference
+            .ticks_since_epoch()
+            .checked_sub(&epoch.ticks_since_epoch())?;
+        let reference_week = reference_offset
+            .div_floor(&week_ticks::<T, S>()?)
+            .to_i64()?;
+
+        // Pick the number of rollovers that puts us closest to the reference week.
+        let rollovers = (reference_week - truncated_week + period / 2).div_euclid(period);
+        let week = truncated_week + rollovers.max(0) * period;
+        Self::new(system, week.to_u32()?, time_of_week)
+    }
+
+    /// Returns the week time of the given instant. Returns None if the instant is before the
+    /// epoch of the system, or if the week number doesn't fit.
+    pub fn from_instant(system: GnssSystem, instant: Instant<T, S>) -> Option<Self> {
+        let epoch = system.epoch::<T, S>()?;
+        let offset = instant
+            .ticks_since_epoch()
+            .checked_sub(&epoch.ticks_since_epoch())?;
+        if offset < T::zero() {
+            return None;
+        }
+        let (week, time_of_week) = offset.div_rem(&week_ticks::<T, S>()?);
+        Some(GnssWeekTime {
+            system,
+            week: week.to_u32()?,
+            time_of_week: Duration::new(time_of_week),
+        })
+    }
+
+    /// Returns the instant of the week time, or None if it doesn't fit in T.
+    pub fn to_instant(&self) -> Option<Instant<T, S>> {
+        let epoch = self.system.epoch::<T, S>()?;
+        let weeks = T::from(self.week)?.checked_mul(&week_ticks::<T, S>()?)?;
+        let ticks = epoch
+            .ticks_since_epoch()
+            .checked_add(&weeks)?
+            .checked_add(&self.time_of_week.ticks())?;
+        Some(Instant::from_ticks_since_epoch(ticks))
+    }
+
+    /// Returns the UTC date and time of the week time, with leap seconds taken from the given
+    /// chronology. Returns None if the result is outside the range of DateTime.
+    pub fn to_date_time(&self, chronology: &Chronology) -> Option<DateTime> {
+        DateTime::from_instant(self.to_instant()?, chronology).ok()
+    }
+
+    pub fn system(&self) -> GnssSystem {
+        self.system
+    }
+
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// Returns the week number truncated to the given number of bits, as it would be
+    /// broadcast by the satellites. With 32 or more bits the week number isn't truncated.
+    pub fn truncated_week(&self, week_bits: u32) -> u32 {
+        if week_bits >= u32::BITS {
+            return self.week;
+        }
+        self.week & ((1 << week_bits) - 1)
+    }
+
+    pub fn time_of_week(&self) -> Duration<T, S> {
+        self.time_of_week
+    }
+}
+
+fn week_ticks<T: Tick, S: Scale>() -> Option<T> {
+    ScaleConversion::<Seconds, S>::convert_exact(T::from(SECONDS_PER_WEEK)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::duration::DurationS64;
+    use crate::instant::InstantS64;
+    use crate::iso8601::{load_chronology, DateTimeBuilder};
+    use crate::scale::Seconds;
+    use crate::DurationNs128;
+
+    #[test]
+    fn gps_epoch() {
+        // Week 0 of GPS time starts on 1980-01-06 00:00:00 UTC.
+        let chronology = load_chronology("UTC");
+        let week_time = GnssWeekTime::new(GnssSystem::Gps, 0, DurationS64::new(0)).unwrap();
+        let expected = DateTimeBuilder::new()
+            .chronology(&chronology)
+            .year(1980)
+            .month(1)
+            .day(6)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .build();
+        assert_eq!(week_time.to_date_time(&chronology).unwrap(), expected);
+
+        // The first week of Galileo time is GPS week 1024.
+        let gps: InstantS64 = GnssWeekTime::new(GnssSystem::Gps, 1024, DurationS64::new(0))
+            .unwrap()
+            .to_instant()
+            .unwrap();
+        assert_eq!(gps, GnssSystem::Galileo.epoch::<i64, Seconds>().unwrap());
+    }
+
+    #[test]
+    fn beidou_is_fourteen_seconds_behind_gps() {
+        let instant: InstantS64 = Instant::from_ticks_since_epoch(1_700_000_000);
+        let gps = GnssWeekTime::from_instant(GnssSystem::Gps, instant).unwrap();
+        let bdt = GnssWeekTime::from_instant(GnssSystem::BeiDou, instant).unwrap();
+        // BeiDou week 0 started 1356 weeks after GPS week 0, 14 seconds later in GPS time.
+        assert_eq!(gps.week() - 1356, bdt.week());
+        assert_eq!(gps.time_of_week().ticks() - 14, bdt.time_of_week().ticks());
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant: Instant<i128, crate::Nanoseconds> =
+            Instant::from_ticks_since_epoch(1_700_000_000_123_456_789);
+        for system in [GnssSystem::Gps, GnssSystem::Galileo, GnssSystem::BeiDou] {
+            let week_time = GnssWeekTime::from_instant(system, instant).unwrap();
+            assert_eq!(week_time.to_instant().unwrap(), instant);
+        }
+
+        let before_epoch = InstantS64::from_ticks_since_epoch(0);
+        assert!(GnssWeekTime::from_instant(GnssSystem::Gps, before_epoch).is_none());
+        assert!(GnssWeekTime::new(
+            GnssSystem::Gps,
+            0,
+            DurationS64::new(SECONDS_PER_WEEK as i64)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn resolve_rollover() {
+        let tow = DurationNs128::new(345_600_000_000_000);
+        let actual = GnssWeekTime::new(GnssSystem::Gps, 2290, tow).unwrap();
+        let truncated = actual.truncated_week(10);
+        assert_eq!(truncated, 2290 - 2048);
+
+        // A reference a few years off still resolves to the right week.
+        let reference = actual.to_instant().unwrap() + DurationNs128::new(-100_000_000_000_000_000);
+        let resolved =
+            GnssWeekTime::resolve_rollover(GnssSystem::Gps, truncated, 10, tow, reference).unwrap();
+        assert_eq!(resolved, actual);
+
+        let reference = actual.to_instant().unwrap() + DurationNs128::new(100_000_000_000_000_000);
+        let resolved =
+            GnssWeekTime::resolve_rollover(GnssSystem::Gps, truncated, 10, tow, reference).unwrap();
+        assert_eq!(resolved, actual);
+
+        // Before the first rollover there's only one candidate.
+        let reference = GnssSystem::Gps.epoch::<i128, crate::Nanoseconds>().unwrap();
+        let resolved =
+            GnssWeekTime::resolve_rollover(GnssSystem::Gps, 10, 10, tow, reference).unwrap();
+        assert_eq!(resolved.week(), 10);
+
+        // Week numbers that don't fit in the given bits, or bits out of range.
+        assert!(
+            GnssWeekTime::resolve_rollover(GnssSystem::Gps, 1024, 10, tow, reference).is_none()
+        );
+        assert!(GnssWeekTime::resolve_rollover(GnssSystem::Gps, 10, 0, tow, reference).is_none());
+        assert!(GnssWeekTime::resolve_rollover(GnssSystem::Gps, 10, 32, tow, reference).is_none());
+        assert_eq!(actual.truncated_week(0), 0);
+        assert_eq!(actual.truncated_week(32), 2290);
+        assert_eq!(actual.truncated_week(64), 2290);
+    }
+}
@@ -50,7 +50,7 @@ impl Debug for Error {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct GregorianNormalizedDate {
     // Number of 400-year cycles since 2000-03-01. With 8 bits we can support
     // 400*127 = 50800 years forward and 400*128 = 51200 years backward. This is
@@ -133,6 +133,19 @@ fn decompose_day_of_cycle(days: u32) -> (u8, u8, u8, u16) {
     (century, quadrennium, years_into_quadrennium, days_into_year)
 }
 
+// Returns the number of days in the month, where the month is zero-based and starts at March.
+fn month_length(century: u8, quadrennium: u8, year: u8, month: u8) -> u8 {
+    if month == 11 {
+        if is_leap_year(century, quadrennium, year) {
+            29
+        } else {
+            28
+        }
+    } else {
+        (year_day_from_month(month + 1) - year_day_from_month(month)) as u8
+    }
+}
+
 impl GregorianNormalizedDate {
     // Because a normalized date starts in March, we can't actually represent the entire
     // minimum year, so we have to add one to prevent January and February from becoming
@@ -198,7 +211,7 @@ impl GregorianNormalizedDate {
         if !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year) {
             return Err(Error::DateOutOfBounds);
         }
-        if !(1..=12).contains(&month) {
+        if !(1..=12).contains(&month) || day == 0 {
             return Err(Error::InvalidDate);
         }
 
@@ -222,15 +235,7 @@ impl GregorianNormalizedDate {
 
         let month_day_offset = year_day_from_month(month);
 
-        let total_days_in_month = if month == 11 {
-            if is_leap_year(century, quadrennium, years_into_quadrennium) {
-                29
-            } else {
-                28
-            }
-        } else {
-            (year_day_from_month(month + 1) - month_day_offset) as u8
-        };
+        let total_days_in_month = month_length(century, quadrennium, years_into_quadrennium, month);
         if day >= total_days_in_month {
             return Err(Error::InvalidDate);
         }
@@ -294,6 +299,11 @@ impl GregorianNormalizedDate {
         day + 1
     }
 
+    pub(crate) fn days_in_month(&self) -> u8 {
+        let month = month_from_year_day(self.day);
+        month_length(self.century, self.quadrennium, self.year, month)
+    }
+
     pub(crate) fn is_unnormalized_leap_year(&self) -> bool {
         // Leap years are at the end of each period: quadrennium, century and cycle.
         // However, because of the way we've shifted the year so that it begins in March,
@@ -358,15 +368,7 @@ impl GregorianNormalizedDate {
 
         self.add_years_no_carry(add_years);
 
-        let total_days_in_month = if month == 11 {
-            if is_leap_year(self.century, self.quadrennium, self.year) {
-                29
-            } else {
-                28
-            }
-        } else {
-            (year_day_from_month(month + 1) - year_day_from_month(month)) as u8
-        };
+        let total_days_in_month = month_length(self.century, self.quadrennium, self.year, month);
 
         let carry = if day_in_month >= total_days_in_month {
             // Need to add +1 because day_in_month is 0-based. If there are
@@ -490,6 +492,14 @@ mod tests {
         assert_eq!(date.quadrennium, 24);
         assert_eq!(date.year, 2);
         assert_eq!(date.day, 364);
+
+        // Months and days are counted from 1, so 0 isn't valid, and neither is a month of 13.
+        for (month, day) in [(0, 1), (13, 1), (1, 0), (2, 30)] {
+            assert!(matches!(
+                GregorianNormalizedDate::from_date(2000, month, day),
+                Err(Error::InvalidDate)
+            ));
+        }
     }
 
     #[test]
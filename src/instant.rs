@@ -1,22 +1,73 @@
 use std::hash::Hash;
 use std::marker::PhantomData;
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::sync::atomic::Ordering::Relaxed;
+use std::sync::atomic::{AtomicU64, AtomicU8};
 use std::time::SystemTime;
 
+use lazy_static::lazy_static;
+
 use num_integer::Integer;
-use num_traits::{Bounded, PrimInt};
-use numcmp::NumCmp;
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedNeg, CheckedRem, CheckedSub, PrimInt, SaturatingMul, WrappingAdd,
+    WrappingMul, WrappingNeg, WrappingSub,
+};
 use thiserror::Error;
 
-use crate::scale::Seconds;
+use crate::clock::{Clock, ClockError, SystemClock};
+use crate::least_common_width::{CommonTick, LeastCommonWidth};
+use crate::rounding::Rounding;
+use crate::scale::{CommonScale, FinerScale, ScaleConversion, Seconds};
 use crate::widen::Widen;
-use crate::zoneinfo::get_leap_second_adjustment_for_unix_timestamp;
+use crate::zoneinfo::{
+    get_leap_second_adjustment_for_instant_seconds, get_leap_second_adjustment_for_unix_timestamp,
+};
 use crate::Nanoseconds;
 use crate::{Duration, Scale};
 
-pub trait Tick: PrimInt + Bounded + Hash + Eq + Copy + Ord + PartialOrd + Integer {}
+pub trait Tick:
+    PrimInt
+    + Bounded
+    + Hash
+    + Eq
+    + Copy
+    + Ord
+    + PartialOrd
+    + Integer
+    + CheckedNeg
+    + CheckedRem
+    + SaturatingMul
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+    + WrappingNeg
+    + OverflowingAdd
+    + OverflowingSub
+    + OverflowingMul
+{
+}
 
-impl<T: PrimInt + Hash + Eq + Copy + Ord + PartialOrd + Integer> Tick for T {}
+impl<T> Tick for T where
+    T: PrimInt
+        + Hash
+        + Eq
+        + Copy
+        + Ord
+        + PartialOrd
+        + Integer
+        + CheckedNeg
+        + CheckedRem
+        + SaturatingMul
+        + WrappingAdd
+        + WrappingSub
+        + WrappingMul
+        + WrappingNeg
+        + OverflowingAdd
+        + OverflowingSub
+        + OverflowingMul
+{
+}
 
 // TODO rename to "moment"? Would make sense given the name of the library.
 #[derive(Debug, Clone, Copy, Hash)]
@@ -33,6 +84,21 @@ pub struct Instant<T: Tick, S: Scale> {
 #[error("instant is out of range")]
 pub struct InstantOutOfRange;
 
+/// An error converting between a [SystemTime] and an [Instant].
+#[derive(Error, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SystemTimeConversionError {
+    /// The time can't be represented in the tick type of the instant, or as a SystemTime.
+    #[error("time is out of range")]
+    OutOfRange,
+    /// Leap seconds need to be added or removed, but the leap-second table couldn't be loaded.
+    #[error("leap-second data is not available")]
+    MissingLeapSecondData,
+    /// The system clock or the system's time functions gave an inconsistent result, e.g. when
+    /// detecting whether the system clock counts leap seconds.
+    #[error("the system clock returned an inconsistent time")]
+    ClockAnomaly,
+}
+
 pub type InstantS32 = Instant<i32, Seconds>;
 pub type InstantS64 = Instant<i64, Seconds>;
 
@@ -101,21 +167,36 @@ impl<T: Tick, S: Scale> Instant<T, S> {
         Instant::from_ticks_since_epoch(self.ticks.widen())
     }
 
+    /// Converts the instant to a coarser scale, rounding towards negative infinity.
     pub fn floor<S2: Scale>(&self) -> Instant<T, S2> {
-        assert!(
-            S2::TICKS_PER_SECOND <= S::TICKS_PER_SECOND,
-            "Cannot floor scale to a higher scale"
-        );
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(S2::TICKS_PER_SECOND).unwrap();
-        Instant::from_ticks_since_epoch(self.ticks.div_floor(&factor))
+        self.round(Rounding::Floor)
+    }
+
+    /// Converts the instant to a coarser scale, rounding according to the mode. Converting to a
+    /// finer scale fails to compile; use [Instant::extend] for that.
+    pub fn round<S2: Scale>(&self, mode: Rounding) -> Instant<T, S2> {
+        let () = ScaleConversion::<S, S2>::COARSER;
+        // The result is never larger in magnitude than the original, so it always fits.
+        let ticks = ScaleConversion::<S, S2>::convert(self.ticks, mode).unwrap();
+        Instant::from_ticks_since_epoch(ticks)
+    }
+
+    /// Rounds the instant to a multiple of the given duration since the epoch, e.g. to the
+    /// nearest 15 minutes. Returns None if the multiple isn't positive or the result can't be
+    /// represented in T.
+    pub fn round_to_multiple(&self, multiple: Duration<T, S>, mode: Rounding) -> Option<Self> {
+        Some(Self::from_duration_since_epoch(
+            self.duration_since_epoch()
+                .round_to_multiple(multiple, mode)?,
+        ))
     }
 
+    /// Splits the instant into an instant of a coarser scale, rounded towards negative infinity,
+    /// and the remaining ticks. Splitting into a scale whose ticks aren't a whole number of ticks
+    /// of this one fails to compile.
     pub fn split<S2: Scale>(&self) -> (Instant<T, S2>, Duration<T, S>) {
-        assert!(
-            S2::TICKS_PER_SECOND <= S::TICKS_PER_SECOND,
-            "Cannot split scale to a higher scale"
-        );
-        let factor = T::from(S::TICKS_PER_SECOND).unwrap() / T::from(S2::TICKS_PER_SECOND).unwrap();
+        let () = ScaleConversion::<S, S2>::WHOLE;
+        let factor = T::from(ScaleConversion::<S, S2>::DIVISOR).unwrap();
         let (ticks, remainder) = self.ticks.div_mod_floor(&factor);
         (
             Instant::from_ticks_since_epoch(ticks),
@@ -123,18 +204,34 @@ impl<T: Tick, S: Scale> Instant<T, S> {
         )
     }
 
+    /// Converts the instant to a finer scale and possibly different tick type. If the ticks of
+    /// this scale aren't a whole number of ticks of S2, the result is rounded towards negative
+    /// infinity. Returns None if the result can't be represented in T2.
     pub fn extend<T2: Tick, S2: Scale>(&self) -> Option<Instant<T2, S2>> {
-        assert!(
-            S2::TICKS_PER_SECOND >= S::TICKS_PER_SECOND,
-            "Cannot extend scale to a lower scale"
-        );
+        let () = ScaleConversion::<S, S2>::FINER;
         let ticks = T2::from(self.ticks)?;
-        let factor =
-            T2::from(S2::TICKS_PER_SECOND).unwrap() / T2::from(S::TICKS_PER_SECOND).unwrap();
-        let ticks = ticks.checked_mul(&factor)?;
+        let ticks = ScaleConversion::<S, S2>::convert(ticks, Rounding::Floor)?;
         Some(Instant::from_ticks_since_epoch(ticks))
     }
 
+    // Returns the instant the given number of seconds and nanoseconds after the epoch, rounded
+    // down to the scale. Returns None if it can't be represented in T.
+    pub(crate) fn from_seconds_and_nanoseconds(seconds: i64, nanosecond: u32) -> Option<Self> {
+        let nanoseconds = seconds as i128 * 1_000_000_000 + nanosecond as i128;
+        let ticks = ScaleConversion::<Nanoseconds, S>::convert(nanoseconds, Rounding::Floor)?;
+        Some(Instant::from_ticks_since_epoch(T::from(ticks)?))
+    }
+
+    // Returns the whole seconds since the epoch and the nanosecond of the second, both rounded
+    // down. Unlike split, this works for scales whose ticks don't divide a second. Returns None
+    // if the seconds don't fit in an i64.
+    pub(crate) fn to_seconds_and_nanoseconds(self) -> Option<(i64, u32)> {
+        let nanoseconds =
+            ScaleConversion::<S, Nanoseconds>::convert(self.ticks.to_i128()?, Rounding::Floor)?;
+        let (seconds, nanosecond) = nanoseconds.div_mod_floor(&1_000_000_000);
+        Some((i64::try_from(seconds).ok()?, nanosecond as u32))
+    }
+
     pub fn duration_since_epoch(&self) -> Duration<T, S> {
         Duration::new(self.ticks)
     }
@@ -143,33 +240,199 @@ impl<T: Tick, S: Scale> Instant<T, S> {
         self.ticks
     }
 
-    pub fn checked_add(&self, rhs: Duration<T, S>) -> Option<Self> {
-        Some(Self::from_ticks_since_epoch(
-            self.ticks.checked_add(&rhs.ticks())?,
-        ))
+    // Arithmetic on instants is arithmetic on the duration since the epoch, so all of these
+    // delegate to the corresponding methods of Duration.
+
+    /// Adds a duration, which may have a different tick type and scale. The result has the
+    /// least common width of the two tick types and the finer of the two scales. Returns None
+    /// if the result can't be represented in that type.
+    pub fn checked_add<T2: Tick, S2: Scale>(
+        &self,
+        rhs: Duration<T2, S2>,
+    ) -> Option<Instant<CommonTick<T, T2>, CommonScale<S, S2>>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        let (lhs, rhs) = self.duration_since_epoch().common_ticks(rhs)?;
+        Some(Instant::from_ticks_since_epoch(CheckedAdd::checked_add(
+            &lhs, &rhs,
+        )?))
+    }
+
+    /// Subtracts a duration, which may have a different tick type and scale. See
+    /// [Instant::checked_add] for the type of the result.
+    pub fn checked_sub<T2: Tick, S2: Scale>(
+        &self,
+        rhs: Duration<T2, S2>,
+    ) -> Option<Instant<CommonTick<T, T2>, CommonScale<S, S2>>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        let (lhs, rhs) = self.duration_since_epoch().common_ticks(rhs)?;
+        Some(Instant::from_ticks_since_epoch(CheckedSub::checked_sub(
+            &lhs, &rhs,
+        )?))
+    }
+
+    /// Returns the duration from `earlier` to this instant, or None if it can't be represented
+    /// in the common tick type (e.g. because it's negative and the type is unsigned).
+    pub fn checked_duration_since<T2: Tick, S2: Scale>(
+        &self,
+        earlier: Instant<T2, S2>,
+    ) -> Option<Duration<CommonTick<T, T2>, CommonScale<S, S2>>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        self.duration_since_epoch()
+            .checked_sub(earlier.duration_since_epoch())
+    }
+
+    /// Returns the duration from `earlier` to this instant, which is negative if `earlier` is
+    /// actually later. Unlike [std::time::Instant::duration_since], this panics if the duration
+    /// can't be represented, the same as subtracting the instants does.
+    pub fn duration_since<T2: Tick, S2: Scale>(
+        &self,
+        earlier: Instant<T2, S2>,
+    ) -> Duration<CommonTick<T, T2>, CommonScale<S, S2>>
+    where
+        T: LeastCommonWidth<T2>,
+        S: FinerScale<S2>,
+    {
+        *self - earlier
+    }
+
+    /// Returns the time that has passed since this instant according to the system clock, which
+    /// is negative if the instant is in the future.
+    pub fn elapsed(&self) -> Result<Duration<T, S>, ClockError> {
+        let now: Instant<T, S> = SystemClock.now()?;
+        now.checked_duration_since(*self)
+            .ok_or(ClockError::OutOfRange)
+    }
+
+    /// Converts the instant to a [SystemTime], removing leap seconds if the system clock doesn't
+    /// count them (see [SystemClockLeapSeconds]). This is the inverse of the conversion from
+    /// SystemTime, except that ticks finer than a nanosecond are rounded down, and a leap second
+    /// and the second after it are the same SystemTime if the system clock doesn't count leap
+    /// seconds.
+    pub fn to_system_time(&self) -> Result<SystemTime, SystemTimeConversionError> {
+        let (mut seconds, nanosecond) = self
+            .to_seconds_and_nanoseconds()
+            .ok_or(SystemTimeConversionError::OutOfRange)?;
+        if !system_time_includes_leap_seconds()? {
+            let leap_seconds = get_leap_second_adjustment_for_instant_seconds(seconds)
+                .ok_or(SystemTimeConversionError::MissingLeapSecondData)?;
+            seconds = seconds
+                .checked_sub(leap_seconds as i64)
+                .ok_or(SystemTimeConversionError::OutOfRange)?;
+        }
+        let since_epoch = std::time::Duration::from_secs(seconds.unsigned_abs());
+        let time = if seconds < 0 {
+            SystemTime::UNIX_EPOCH.checked_sub(since_epoch)
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(since_epoch)
+        };
+        time.and_then(|time| time.checked_add(std::time::Duration::from_nanos(nanosecond as u64)))
+            .ok_or(SystemTimeConversionError::OutOfRange)
+    }
+
+    pub fn saturating_add(&self, rhs: Duration<T, S>) -> Self {
+        Self::from_duration_since_epoch(self.duration_since_epoch().saturating_add(rhs))
+    }
+
+    pub fn saturating_sub(&self, rhs: Duration<T, S>) -> Self {
+        Self::from_duration_since_epoch(self.duration_since_epoch().saturating_sub(rhs))
+    }
+
+    pub fn saturating_duration_since(&self, earlier: Self) -> Duration<T, S> {
+        self.duration_since_epoch()
+            .saturating_sub(earlier.duration_since_epoch())
+    }
+
+    pub fn wrapping_add(&self, rhs: Duration<T, S>) -> Self {
+        Self::from_duration_since_epoch(self.duration_since_epoch().wrapping_add(rhs))
+    }
+
+    pub fn wrapping_sub(&self, rhs: Duration<T, S>) -> Self {
+        Self::from_duration_since_epoch(self.duration_since_epoch().wrapping_sub(rhs))
+    }
+
+    pub fn wrapping_duration_since(&self, earlier: Self) -> Duration<T, S> {
+        self.duration_since_epoch()
+            .wrapping_sub(earlier.duration_since_epoch())
+    }
+
+    pub fn overflowing_add(&self, rhs: Duration<T, S>) -> (Self, bool) {
+        let (duration, overflow) = self.duration_since_epoch().overflowing_add(rhs);
+        (Self::from_duration_since_epoch(duration), overflow)
+    }
+
+    pub fn overflowing_sub(&self, rhs: Duration<T, S>) -> (Self, bool) {
+        let (duration, overflow) = self.duration_since_epoch().overflowing_sub(rhs);
+        (Self::from_duration_since_epoch(duration), overflow)
+    }
+
+    pub fn overflowing_duration_since(&self, earlier: Self) -> (Duration<T, S>, bool) {
+        self.duration_since_epoch()
+            .overflowing_sub(earlier.duration_since_epoch())
+    }
+
+    fn from_duration_since_epoch(duration: Duration<T, S>) -> Self {
+        Self::from_ticks_since_epoch(duration.ticks())
     }
 }
 
-impl<T: Tick, S: Scale> Sub for Instant<T, S> {
-    type Output = Duration<T, S>;
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> Sub<Instant<T2, S2>> for Instant<T1, S1>
+where
+    T1: LeastCommonWidth<T2>,
+    S1: FinerScale<S2>,
+{
+    type Output = Duration<CommonTick<T1, T2>, CommonScale<S1, S2>>;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Duration::new(
-            self.ticks
-                .checked_sub(&rhs.ticks)
-                .expect("instant subtraction underflow"),
-        )
+    fn sub(self, rhs: Instant<T2, S2>) -> Self::Output {
+        self.checked_duration_since(rhs)
+            .expect("instant subtraction underflow")
     }
 }
 
-impl<T: Tick, S: Scale> Add<Duration<T, S>> for Instant<T, S> {
-    type Output = Self;
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> Add<Duration<T2, S2>> for Instant<T1, S1>
+where
+    T1: LeastCommonWidth<T2>,
+    S1: FinerScale<S2>,
+{
+    type Output = Instant<CommonTick<T1, T2>, CommonScale<S1, S2>>;
 
-    fn add(self, rhs: Duration<T, S>) -> Self::Output {
+    fn add(self, rhs: Duration<T2, S2>) -> Self::Output {
         self.checked_add(rhs).expect("instant addition overflow")
     }
 }
 
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> Sub<Duration<T2, S2>> for Instant<T1, S1>
+where
+    T1: LeastCommonWidth<T2>,
+    S1: FinerScale<S2>,
+{
+    type Output = Instant<CommonTick<T1, T2>, CommonScale<S1, S2>>;
+
+    fn sub(self, rhs: Duration<T2, S2>) -> Self::Output {
+        self.checked_sub(rhs).expect("instant subtraction overflow")
+    }
+}
+
+impl<T: Tick, S: Scale> AddAssign<Duration<T, S>> for Instant<T, S> {
+    fn add_assign(&mut self, rhs: Duration<T, S>) {
+        *self = self.checked_add(rhs).expect("instant addition overflow");
+    }
+}
+
+impl<T: Tick, S: Scale> SubAssign<Duration<T, S>> for Instant<T, S> {
+    fn sub_assign(&mut self, rhs: Duration<T, S>) {
+        *self = self.checked_sub(rhs).expect("instant subtraction overflow");
+    }
+}
+
 /*impl<T: Tick, S1: Scale, S2: Scale> PartialEq<Instant<T, S2>> for Instant<T, S1> {
     fn eq(&self, other: &Instant<T, S2>) -> bool {
         // If
@@ -207,10 +470,7 @@ impl<T: Tick, S: Scale> Add<Duration<T, S>> for Instant<T, S> {
     }
 }*/
 
-impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> PartialEq<Instant<T2, S2>> for Instant<T1, S1>
-where
-    T1: NumCmp<T2>,
-{
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> PartialEq<Instant<T2, S2>> for Instant<T1, S1> {
     fn eq(&self, other: &Instant<T2, S2>) -> bool {
         // If
         //
@@ -220,34 +480,9 @@ where
         //
         // t1*s2 == t2*s1
         //
-        // If s1 < s2 then this can be rewritten as
-        //
-        // t1 == t2*s2/s1.
-        //
-        // If s1 > s2, it can be rewritten as
-        //
-        // t1*s1/s2 == t2.
-        //
-        // If s1 == s2, then it simplifies to
-        //
-        // t1 == t2.
-        let t1 = self.ticks;
-        let s1 = S1::TICKS_PER_SECOND;
-        let t2 = other.ticks;
-        let s2 = S2::TICKS_PER_SECOND;
-        match s1.cmp(&s2) {
-            std::cmp::Ordering::Less => {
-                let factor =
-                    T2::from(s2 / s1).expect("scale conversion factor is too large for type T2");
-                t1.num_eq(t2 * factor)
-            }
-            std::cmp::Ordering::Greater => {
-                let factor =
-                    T1::from(s1 / s2).expect("scale conversion factor is too large for type T1");
-                (t1 * factor).num_eq(t2)
-            }
-            std::cmp::Ordering::Equal => t1.num_eq(t2),
-        }
+        // which the scale conversion compares exactly, with the factors reduced and without
+        // overflowing.
+        ScaleConversion::<S1, S2>::cmp(self.ticks, other.ticks) == std::cmp::Ordering::Equal
     }
 }
 
@@ -277,10 +512,7 @@ where
     }
 }*/
 
-impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> PartialOrd<Instant<T2, S2>> for Instant<T1, S1>
-where
-    T1: NumCmp<T2>,
-{
+impl<T1: Tick, S1: Scale, T2: Tick, S2: Scale> PartialOrd<Instant<T2, S2>> for Instant<T1, S1> {
     fn partial_cmp(&self, other: &Instant<T2, S2>) -> Option<std::cmp::Ordering> {
         // Similar to PartialEq, if
         //
@@ -290,23 +522,7 @@ where
         //
         // t1*s2 < t2*s1
         //
-        let t1 = self.ticks;
-        let s1 = S1::TICKS_PER_SECOND;
-        let t2 = other.ticks;
-        let s2 = S2::TICKS_PER_SECOND;
-        match s1.cmp(&s2) {
-            std::cmp::Ordering::Less => {
-                let factor =
-                    T2::from(s2 / s1).expect("scale conversion factor is too large for type T2");
-                t1.num_cmp(t2 * factor)
-            }
-            std::cmp::Ordering::Greater => {
-                let factor =
-                    T1::from(s1 / s2).expect("scale conversion factor is too large for type T1");
-                (t1 * factor).num_cmp(t2)
-            }
-            std::cmp::Ordering::Equal => t1.num_cmp(t2),
-        }
+        Some(ScaleConversion::<S1, S2>::cmp(self.ticks, other.ticks))
     }
 }
 
@@ -323,53 +539,143 @@ where
 }*/
 
 impl<T: Tick, S: Scale> TryFrom<SystemTime> for Instant<T, S> {
-    type Error = std::time::SystemTimeError;
+    type Error = SystemTimeConversionError;
 
     fn try_from(value: SystemTime) -> Result<Self, Self::Error> {
-        let ticks_per_second =
-            T::from(S::TICKS_PER_SECOND).expect("ticks per second is too large for type T)");
-        let nanoseconds_per_second =
-            T::from(1_000_000_000).expect("nanoseconds per second is too large for type T)");
-        let nanoseconds_per_tick = nanoseconds_per_second / ticks_per_second;
-
-        let (mut seconds, subsecond_ns) = system_time_to_time_t(value);
-        if !system_time_includes_leap_seconds() {
-            seconds += get_leap_second_adjustment_for_unix_timestamp(seconds) as i64;
+        let (mut seconds, subsecond_ns) = system_time_to_time_t(value)?;
+        if !system_time_includes_leap_seconds()? {
+            let leap_seconds = get_leap_second_adjustment_for_unix_timestamp(seconds)
+                .ok_or(SystemTimeConversionError::MissingLeapSecondData)?;
+            seconds = seconds
+                .checked_add(leap_seconds as i64)
+                .ok_or(SystemTimeConversionError::OutOfRange)?;
         }
+        Instant::from_seconds_and_nanoseconds(seconds, subsecond_ns)
+            .ok_or(SystemTimeConversionError::OutOfRange)
+    }
+}
 
-        // FIXME report proper error here
-        let seconds = T::from(seconds).unwrap();
-        // FIXME and here
-        let subsecond_ns = T::from(subsecond_ns).unwrap();
-
-        let subsec_ticks = subsecond_ns / nanoseconds_per_tick;
-        let total_ticks = seconds * ticks_per_second + subsec_ticks;
+impl<T: Tick, S: Scale> TryFrom<Instant<T, S>> for SystemTime {
+    type Error = SystemTimeConversionError;
 
-        Ok(Instant::<T, S> {
-            ticks: total_ticks,
-            phantom: PhantomData,
-        })
+    fn try_from(value: Instant<T, S>) -> Result<Self, Self::Error> {
+        value.to_system_time()
     }
 }
 
 /// Return the number of seconds and nanoseconds since the Unix epoch, as
 /// defined by time_t (i.e. without caring about leap seconds).
-fn system_time_to_time_t(value: SystemTime) -> (libc::time_t, u32) {
+fn system_time_to_time_t(
+    value: SystemTime,
+) -> Result<(libc::time_t, u32), SystemTimeConversionError> {
     let sign = value < SystemTime::UNIX_EPOCH;
     let duration = if sign {
         SystemTime::UNIX_EPOCH.duration_since(value)
     } else {
         value.duration_since(SystemTime::UNIX_EPOCH)
     }
-    .unwrap();
-    let mut seconds = duration.as_secs() as i64;
-    if sign {
-        seconds = -seconds;
+    // Can't happen since we just compared them, unless SystemTime is inconsistent.
+    .map_err(|_| SystemTimeConversionError::ClockAnomaly)?;
+    let seconds = libc::time_t::try_from(duration.as_secs())
+        .map_err(|_| SystemTimeConversionError::OutOfRange)?;
+    let nanoseconds = duration.subsec_nanos();
+    Ok(if !sign {
+        (seconds, nanoseconds)
+    } else if nanoseconds == 0 {
+        (-seconds, 0)
+    } else {
+        // The nanoseconds count forward from the start of the second, also before the epoch.
+        (-seconds - 1, 1_000_000_000 - nanoseconds)
+    })
+}
+
+/// How to determine whether the system clock counts leap seconds when converting a [SystemTime]
+/// to an [Instant].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SystemClockLeapSeconds {
+    /// Detect it at runtime. The result is cached process-wide and detected again when the
+    /// refresh interval has passed (see [set_system_clock_leap_second_refresh_interval]).
+    Detect = 0,
+    /// The system clock follows POSIX time, i.e. every day is 86,400 seconds long and leap
+    /// seconds are not counted.
+    Posix = 1,
+    /// The system clock counts leap seconds, as when the system time zone is one of the
+    /// "right/" time zones.
+    Right = 2,
+}
+
+// Cached state for system_time_includes_leap_seconds. The detection result is stored as
+// DETECTED_UNKNOWN, DETECTED_POSIX or DETECTED_RIGHT, and the time of the next detection is
+// stored as nanoseconds since DETECTION_CLOCK_BASE. All of these are independent so relaxed
+// ordering is enough; in the worst case two threads run the detection at the same time.
+const DETECTED_UNKNOWN: u8 = 0;
+const DETECTED_POSIX: u8 = 1;
+const DETECTED_RIGHT: u8 = 2;
+const DEFAULT_REFRESH_INTERVAL_NS: u64 = 60_000_000_000;
+
+static LEAP_SECOND_MODE: AtomicU8 = AtomicU8::new(SystemClockLeapSeconds::Detect as u8);
+static DETECTED_LEAP_SECONDS: AtomicU8 = AtomicU8::new(DETECTED_UNKNOWN);
+static NEXT_DETECTION_NS: AtomicU64 = AtomicU64::new(0);
+static REFRESH_INTERVAL_NS: AtomicU64 = AtomicU64::new(DEFAULT_REFRESH_INTERVAL_NS);
+
+lazy_static! {
+    static ref DETECTION_CLOCK_BASE: std::time::Instant = std::time::Instant::now();
+}
+
+/// Set how to determine whether the system clock counts leap seconds. The default is
+/// [SystemClockLeapSeconds::Detect].
+pub fn set_system_clock_leap_seconds(mode: SystemClockLeapSeconds) {
+    LEAP_SECOND_MODE.store(mode as u8, Relaxed);
+    // Forget any earlier detection so that switching back to Detect detects again.
+    DETECTED_LEAP_SECONDS.store(DETECTED_UNKNOWN, Relaxed);
+}
+
+pub fn system_clock_leap_seconds() -> SystemClockLeapSeconds {
+    match LEAP_SECOND_MODE.load(Relaxed) {
+        1 => SystemClockLeapSeconds::Posix,
+        2 => SystemClockLeapSeconds::Right,
+        _ => SystemClockLeapSeconds::Detect,
     }
-    (seconds, duration.subsec_nanos())
 }
 
-/// Return true if the system time includes leap seconds.
+/// Set how often the cached result of leap-second detection is refreshed when using
+/// [SystemClockLeapSeconds::Detect]. The default is one minute.
+pub fn set_system_clock_leap_second_refresh_interval(interval: std::time::Duration) {
+    let interval = interval.as_nanos().min(u64::MAX as u128) as u64;
+    REFRESH_INTERVAL_NS.store(interval, Relaxed);
+    NEXT_DETECTION_NS.store(0, Relaxed);
+}
+
+/// Return true if the system time includes leap seconds, according to the configured
+/// [SystemClockLeapSeconds] mode.
+fn system_time_includes_leap_seconds() -> Result<bool, SystemTimeConversionError> {
+    match system_clock_leap_seconds() {
+        SystemClockLeapSeconds::Posix => return Ok(false),
+        SystemClockLeapSeconds::Right => return Ok(true),
+        SystemClockLeapSeconds::Detect => {}
+    }
+
+    let now = DETECTION_CLOCK_BASE.elapsed().as_nanos() as u64;
+    let detected = DETECTED_LEAP_SECONDS.load(Relaxed);
+    if detected != DETECTED_UNKNOWN && now < NEXT_DETECTION_NS.load(Relaxed) {
+        return Ok(detected == DETECTED_RIGHT);
+    }
+
+    let includes_leap_seconds = detect_system_time_includes_leap_seconds()?;
+    let detected = if includes_leap_seconds {
+        DETECTED_RIGHT
+    } else {
+        DETECTED_POSIX
+    };
+    DETECTED_LEAP_SECONDS.store(detected, Relaxed);
+    NEXT_DETECTION_NS.store(
+        now.saturating_add(REFRESH_INTERVAL_NS.load(Relaxed)),
+        Relaxed,
+    );
+    Ok(includes_leap_seconds)
+}
+
+/// Detect whether the system time includes leap seconds.
 ///
 /// The Rust documentation for SystemTime specifically says "A SystemTime does not count leap seconds."
 /// But it also says that it uses clock_gettime() with CLOCK_REALTIME, which is documented to say
@@ -381,11 +687,9 @@ fn system_time_to_time_t(value: SystemTime) -> (libc::time_t, u32) {
 /// on the OS to follow its own documentation, this function attempts to check for it by using the same
 /// facilities to get a time_t value and a gregorian date, and then comparing the time_t value to what
 /// it should be given the system's idea of the current date and time.
-fn system_time_includes_leap_seconds() -> bool {
-    // TODO cache the result of this, but refresh periodically in case things change.
-
+fn detect_system_time_includes_leap_seconds() -> Result<bool, SystemTimeConversionError> {
     let now = SystemTime::now();
-    let (seconds, _) = system_time_to_time_t(now);
+    let (seconds, _) = system_time_to_time_t(now)?;
     let mut georgian = libc::tm {
         tm_sec: 0,
         tm_min: 0,
@@ -399,8 +703,9 @@ fn system_time_includes_leap_seconds() -> bool {
         tm_gmtoff: 0,
         tm_zone: std::ptr::null_mut(),
     };
-    unsafe {
-        libc::gmtime_r(&seconds, &mut georgian);
+    // gmtime_r only fails if the year doesn't fit in an int, which means the clock is way off.
+    if unsafe { libc::gmtime_r(&seconds, &mut georgian) }.is_null() {
+        return Err(SystemTimeConversionError::ClockAnomaly);
     }
     // Compute what the time_t value *should* be if SystemTime returns Unix time (i.e. no leap seconds).
     // If the time_t value is different, then the system time includes leap seconds.
@@ -413,7 +718,7 @@ fn system_time_includes_leap_seconds() -> bool {
         georgian.tm_sec,
     );
 
-    unix_time != seconds
+    Ok(unix_time != seconds)
 }
 
 fn utc_to_unix_time(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32) -> i64 {
@@ -443,19 +748,12 @@ fn julian_day(year: i32, month: i32, day: i32) -> i32 {
     jdn as i32
 }
 
-// TODO how do I implement into() for SystemTime?
-/*impl<T: PrimInt, S: Precision> From<SystemTime> for Instant<T, S> {
-    fn from(value: SystemTime) -> Self {
-        match Self::try_from(value) {
-            Ok(instant) => instant,
-            Err(err) => panic!("failed to convert SystemTime to Instant: {}", err),
-        }
-    }
-}*/
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scale::{
+        Attoseconds, Deciseconds, Microseconds, Milliseconds, Picoseconds, Timebase,
+    };
 
     #[test]
     fn it_works() {
@@ -466,4 +764,263 @@ mod tests {
         let t2: InstantNs128 = SystemTime::now().try_into().unwrap();
         assert!(t1 <= t2);
     }
+
+    #[test]
+    fn arithmetic() {
+        let max = InstantS64::max_value();
+        let one = Duration::new(1);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(max.wrapping_add(one), InstantS64::min_value());
+        assert_eq!(max.overflowing_add(one), (InstantS64::min_value(), true));
+
+        let mut instant = InstantS64::from_ticks_since_epoch(10);
+        instant -= one;
+        assert_eq!(instant - one, InstantS64::from_ticks_since_epoch(8));
+        instant += Duration::new(3);
+        assert_eq!(instant.ticks_since_epoch(), 12);
+
+        let earlier = Instant::<u32, Seconds>::from_ticks_since_epoch(5);
+        let later = Instant::<u32, Seconds>::from_ticks_since_epoch(3);
+        assert_eq!(later.checked_duration_since(earlier), None);
+        assert_eq!(later.saturating_duration_since(earlier).ticks(), 0);
+        assert_eq!(
+            earlier.checked_duration_since(later).map(|d| d.ticks()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let instant = InstantNs128::from_ticks_since_epoch(-1);
+        assert_eq!(instant.floor::<Seconds>().ticks_since_epoch(), -1);
+        assert_eq!(
+            instant
+                .round::<Seconds>(Rounding::HalfUp)
+                .ticks_since_epoch(),
+            0
+        );
+        let (seconds, subsecond) = instant.split::<Seconds>();
+        assert_eq!(seconds.ticks_since_epoch(), -1);
+        assert_eq!(subsecond.ticks(), 999_999_999);
+
+        let quarter = Duration::from_minutes(15).unwrap();
+        let instant = InstantS64::from_ticks_since_epoch(1_700_000_000);
+        let rounded = instant
+            .round_to_multiple(quarter, Rounding::HalfEven)
+            .unwrap();
+        assert_eq!(rounded.ticks_since_epoch(), 1_700_000_100);
+    }
+
+    #[test]
+    fn mixed_arithmetic() {
+        use crate::scale::Milliseconds;
+
+        let instant = InstantNs128::from_ticks_since_epoch(1_000_000_001);
+        let duration = Duration::<i64, Milliseconds>::new(1_500);
+        let sum: InstantNs128 = instant + duration;
+        assert_eq!(sum.ticks_since_epoch(), 2_500_000_001);
+        assert_eq!((sum - duration).ticks_since_epoch(), 1_000_000_001);
+
+        let seconds = InstantS32::from_ticks_since_epoch(3);
+        let milliseconds = Instant::<i64, Milliseconds>::from_ticks_since_epoch(1_250);
+        let difference: Duration<i64, Milliseconds> = seconds - milliseconds;
+        assert_eq!(difference.ticks(), 1_750);
+
+        // 100 s doesn't fit in an i8 of milliseconds.
+        let small = Instant::<i8, Seconds>::from_ticks_since_epoch(100);
+        assert_eq!(
+            small.checked_add(Duration::<i8, Milliseconds>::new(1)),
+            None
+        );
+        assert_eq!(
+            small
+                .checked_add(Duration::<i64, Milliseconds>::new(1))
+                .map(|i| i.ticks_since_epoch()),
+            Some(100_001)
+        );
+    }
+
+    // 1/65,536 of a second, as used by some network protocols.
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+    struct Binary16;
+
+    impl Scale for Binary16 {
+        const TICKS_PER_SECOND: u64 = 65_536;
+    }
+
+    #[test]
+    fn user_defined_and_sub_nanosecond_scales() {
+        // Half a second and one tick of 1/65,536 s (15,258.789... ns).
+        let t = Instant::<i64, Binary16>::from_ticks_since_epoch(32_769);
+        let ns: Instant<i64, Nanoseconds> = t.extend().unwrap();
+        assert_eq!(ns.ticks_since_epoch(), 500_015_258);
+        assert_ne!(t, ns);
+        assert!(t > ns);
+        assert_eq!(
+            ns.round::<Binary16>(Rounding::HalfUp).ticks_since_epoch(),
+            32_769
+        );
+        assert_eq!(ns.floor::<Binary16>().ticks_since_epoch(), 32_768);
+        assert_eq!(t.floor::<Milliseconds>().ticks_since_epoch(), 500);
+        assert_eq!(
+            t.split::<Seconds>(),
+            (Instant::from_ticks_since_epoch(0), Duration::new(32_769))
+        );
+
+        let t = Instant::<i128, Attoseconds>::from_ticks_since_epoch(1_500_000_000_000_000_001);
+        assert_eq!(
+            t.floor::<Picoseconds>().ticks_since_epoch(),
+            1_500_000_000_000
+        );
+        assert_eq!(
+            t.round::<Microseconds>(Rounding::Ceil).ticks_since_epoch(),
+            1_500_001
+        );
+        assert_eq!(t.floor::<Deciseconds>().ticks_since_epoch(), 15);
+        assert!(t > Instant::<i64, Deciseconds>::from_ticks_since_epoch(15));
+    }
+
+    #[test]
+    fn rational_scales() {
+        type Ntsc = Timebase<30_000, 1_001>;
+        let frame = Instant::<i64, Ntsc>::from_ticks_since_epoch(30_001);
+        assert!(frame > InstantS64::from_ticks_since_epoch(1_001));
+        assert_eq!(
+            Instant::<i64, Ntsc>::from_ticks_since_epoch(30_000),
+            InstantS64::from_ticks_since_epoch(1_001)
+        );
+        let ns: Instant<i64, Nanoseconds> = frame.extend().unwrap();
+        assert_eq!(ns.ticks_since_epoch(), 1_001_033_366_666);
+        assert_eq!(ns.round::<Ntsc>(Rounding::HalfEven), frame);
+        assert_eq!(frame.floor::<Seconds>().ticks_since_epoch(), 1_001);
+        assert_eq!(
+            frame.to_seconds_and_nanoseconds(),
+            Some((1_001, 33_366_666))
+        );
+        assert_eq!(
+            Instant::<i64, Ntsc>::from_seconds_and_nanoseconds(1_001, 33_366_667),
+            Some(frame)
+        );
+    }
+
+    #[test]
+    fn system_time_round_trip() {
+        // The mode isn't changed by other tests, see system_clock_leap_seconds_override.
+        let leap_seconds = if detect_system_time_includes_leap_seconds().unwrap() {
+            0
+        } else {
+            27
+        };
+        // 2017-01-01 00:00:00.0000005 UTC, after the 27th leap second.
+        let instant =
+            Instant::<i64, Nanoseconds>::from_ticks_since_epoch(1_483_228_827_000_000_500);
+        let system_time = instant.to_system_time().unwrap();
+        assert_eq!(
+            system_time.duration_since(SystemTime::UNIX_EPOCH).unwrap(),
+            std::time::Duration::new(1_483_228_827 - leap_seconds, 500)
+        );
+        let converted: Instant<i64, Nanoseconds> = system_time.try_into().unwrap();
+        assert_eq!(converted, instant);
+        assert_eq!(SystemTime::try_from(instant).ok(), Some(system_time));
+
+        // Before 1972 there are no leap seconds to remove.
+        let instant = InstantNs128::from_ticks_since_epoch(-1_500_000_000);
+        let system_time = instant.to_system_time().unwrap();
+        assert_eq!(
+            SystemTime::UNIX_EPOCH.duration_since(system_time).unwrap(),
+            std::time::Duration::from_millis(1_500)
+        );
+        let converted: InstantNs128 = system_time.try_into().unwrap();
+        assert_eq!(converted, instant);
+
+        // Ticks finer than a nanosecond are rounded down.
+        let instant = Instant::<i64, Picoseconds>::from_ticks_since_epoch(-1);
+        assert_eq!(
+            instant.to_system_time().unwrap(),
+            SystemTime::UNIX_EPOCH - std::time::Duration::from_nanos(1)
+        );
+    }
+
+    #[test]
+    fn system_time_errors() {
+        let now = SystemTime::now();
+        assert_eq!(
+            from_system_time::<i32, Nanoseconds>(now),
+            Err(SystemTimeConversionError::OutOfRange)
+        );
+        assert!(from_system_time::<i64, Nanoseconds>(now).is_ok());
+
+        // About 200 years before the epoch, and 300 years after it.
+        let years = std::time::Duration::from_secs(200 * 31_556_952);
+        let pre_1970 = SystemTime::UNIX_EPOCH - years;
+        let instant = from_system_time::<i64, Seconds>(pre_1970).unwrap();
+        assert_eq!(instant.ticks_since_epoch(), -6_311_390_400);
+        assert_eq!(instant.to_system_time(), Ok(pre_1970));
+        assert_eq!(
+            from_system_time::<i32, Seconds>(pre_1970),
+            Err(SystemTimeConversionError::OutOfRange)
+        );
+        let far_future = SystemTime::UNIX_EPOCH + years / 2 * 3;
+        assert!(from_system_time::<i64, Seconds>(far_future).is_ok());
+        assert_eq!(
+            from_system_time::<i64, Nanoseconds>(far_future),
+            Err(SystemTimeConversionError::OutOfRange)
+        );
+
+        // The largest SystemTime has more seconds than an Instant can count once leap seconds
+        // are added, or else more than an i128 of attoseconds can hold.
+        let last = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(i64::MAX as u64);
+        assert_eq!(
+            from_system_time::<i128, Attoseconds>(last),
+            Err(SystemTimeConversionError::OutOfRange)
+        );
+        assert_eq!(
+            InstantS128::from_ticks_since_epoch(i64::MIN as i128 - 1).to_system_time(),
+            Err(SystemTimeConversionError::OutOfRange)
+        );
+    }
+
+    // The TryFrom trait is shadowed by Instant::try_from.
+    fn from_system_time<T: Tick, S: Scale>(
+        time: SystemTime,
+    ) -> Result<Instant<T, S>, SystemTimeConversionError> {
+        time.try_into()
+    }
+
+    #[test]
+    fn duration_since_and_elapsed() {
+        let t1 = InstantS64::from_ticks_since_epoch(10);
+        let t2 = Instant::<i32, Milliseconds>::from_ticks_since_epoch(9_500);
+        assert_eq!(t1.duration_since(t2), Duration::new(500i64));
+        assert_eq!(t2.duration_since(t1), Duration::new(-500i64));
+
+        let start: InstantNs128 = SystemTime::now().try_into().unwrap();
+        let elapsed = start.elapsed().unwrap();
+        assert!(elapsed >= Duration::new(0));
+        assert!(elapsed < Duration::new(60_000_000_000));
+    }
+
+    #[test]
+    fn system_clock_leap_seconds_override() {
+        // Other tests convert system times concurrently, so only force the mode that detection
+        // would give anyway.
+        let detected = detect_system_time_includes_leap_seconds().unwrap();
+        let forced = if detected {
+            SystemClockLeapSeconds::Right
+        } else {
+            SystemClockLeapSeconds::Posix
+        };
+        assert_eq!(system_time_includes_leap_seconds(), Ok(detected));
+
+        set_system_clock_leap_seconds(forced);
+        assert_eq!(system_clock_leap_seconds(), forced);
+        assert_eq!(system_time_includes_leap_seconds(), Ok(detected));
+
+        set_system_clock_leap_seconds(SystemClockLeapSeconds::Detect);
+        set_system_clock_leap_second_refresh_interval(std::time::Duration::ZERO);
+        assert_eq!(system_time_includes_leap_seconds(), Ok(detected));
+        set_system_clock_leap_second_refresh_interval(std::time::Duration::from_secs(60));
+        assert_eq!(system_clock_leap_seconds(), SystemClockLeapSeconds::Detect);
+    }
 }
@@ -0,0 +1,206 @@
+use crate::iso8601::carry_policy::CarryPolicy;
+use crate::iso8601::{DateTime, Period};
+
+/// An iterator over a schedule of DateTimes that are a whole number of steps from an anchor.
+///
+/// Each DateTime is computed from the anchor rather than from the one before it, so the schedule
+/// doesn't drift when a step has to be clamped. Monthly steps from January 31 give February 28
+/// and then March 31, while adding a month at a time gives February 28 and then March 28.
+/// Likewise, yearly steps from 23:59:60 on a day with a leap second land on 23:59:60 again
+/// whenever the day has a leap second.
+///
+/// The iterator starts with the anchor itself and ends when a step is out of the range of
+/// DateTime. Steps whose carry is rejected by [CarryPolicy::Reject] are skipped.
+#[derive(Debug, Clone)]
+pub struct AnchoredSteps {
+    anchor: DateTime,
+    step: Period,
+    carry_policy: CarryPolicy,
+    index: i64,
+}
+
+impl AnchoredSteps {
+    pub fn anchor(&self) -> &DateTime {
+        &self.anchor
+    }
+
+    pub fn step(&self) -> &Period {
+        &self.step
+    }
+
+    /// Returns the DateTime the given number of steps from the anchor, which goes backwards if
+    /// the number is negative. Returns None if it is out of range or rejected by the carry
+    /// policy.
+    pub fn nth_step(&self, steps: i64) -> Option<DateTime> {
+        self.anchor
+            .checked_add_period_with(&self.step.checked_mul(steps)?, self.carry_policy)
+    }
+}
+
+impl Iterator for AnchoredSteps {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let steps = self.index;
+            self.index = self.index.checked_add(1)?;
+            if let Some(date_time) = self.nth_step(steps) {
+                return Some(date_time);
+            }
+            // A step that is in range when its carry is dropped was rejected by the policy.
+            let period = self.step.checked_mul(steps)?;
+            self.anchor.checked_add_period(&period)?;
+        }
+    }
+}
+
+impl DateTime {
+    /// Returns the schedule of DateTimes that are whole multiples of the step from this one, with
+    /// any carry resolved by the given policy. See [AnchoredSteps].
+    pub fn anchored_steps(&self, step: Period, carry_policy: CarryPolicy) -> AnchoredSteps {
+        AnchoredSteps {
+            anchor: self.clone(),
+            step,
+            carry_policy,
+            index: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::load_chronology;
+
+    #[test]
+    fn monthly_steps_stay_on_month_ends() {
+        let chronology = load_chronology("UTC");
+        let date = |year, month, day| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(9)
+                .minute(30)
+                .second(0)
+                .build()
+        };
+        let monthly = Period {
+            months: 1,
+            ..Default::default()
+        };
+        let anchor = date(2024, 1, 31);
+
+        let steps: Vec<_> = anchor
+            .anchored_steps(monthly, CarryPolicy::Constrain)
+            .take(5)
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                date(2024, 1, 31),
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                date(2024, 4, 30),
+                date(2024, 5, 31),
+            ]
+        );
+
+        // Adding a month at a time drifts.
+        let drifted = anchor.add_months(1).drop_carry().add_months(1).drop_carry();
+        assert_eq!(drifted, date(2024, 3, 29));
+
+        let steps: Vec<_> = anchor
+            .anchored_steps(monthly, CarryPolicy::Reject)
+            .take(4)
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                date(2024, 1, 31),
+                date(2024, 3, 31),
+                date(2024, 5, 31),
+                date(2024, 7, 31),
+            ]
+        );
+
+        let steps = anchor.anchored_steps(monthly, CarryPolicy::Overflow);
+        assert_eq!(steps.nth_step(1), Some(date(2024, 3, 2)));
+        assert_eq!(steps.nth_step(-2), Some(date(2023, 12, 1)));
+        assert_eq!(steps.nth_step(3), Some(date(2024, 5, 1)));
+    }
+
+    #[test]
+    fn yearly_steps_on_leap_second() {
+        let chronology = load_chronology("UTC");
+        let end_of_year = |year, second| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(12)
+                .day(31)
+                .hour(23)
+                .minute(59)
+                .second(second)
+                .build()
+        };
+        let yearly = Period {
+            years: 1,
+            ..Default::default()
+        };
+        // Leap seconds were inserted at the end of 2005, 2008 and 2016.
+        let steps: Vec<_> = end_of_year(2005, 60)
+            .anchored_steps(yearly, CarryPolicy::Constrain)
+            .take(4)
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                end_of_year(2005, 60),
+                end_of_year(2006, 59),
+                end_of_year(2007, 59),
+                end_of_year(2008, 60),
+            ]
+        );
+    }
+
+    #[test]
+    fn steps_end_at_the_end_of_the_range() {
+        let chronology = load_chronology("UTC");
+        let anchor = DateTime::builder()
+            .chronology(&chronology)
+            .year(9997)
+            .month(6)
+            .day(15)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .build();
+        let steps = anchor.anchored_steps(
+            Period {
+                years: 1,
+                ..Default::default()
+            },
+            CarryPolicy::Reject,
+        );
+        assert_eq!(steps.anchor(), &anchor);
+        assert_eq!(steps.count(), 3);
+
+        // A whole number of 400-year cycles is out of range rather than back at the anchor.
+        let monthly = anchor.anchored_steps(
+            Period {
+                months: 1,
+                ..Default::default()
+            },
+            CarryPolicy::Constrain,
+        );
+        assert_eq!(monthly.nth_step(786_432), None);
+        assert_eq!(monthly.nth_step(-786_432), None);
+        assert_eq!(monthly.nth_step(i64::MAX), None);
+        assert_eq!(
+            monthly.nth_step(-9997 * 12 - 5),
+            Some(anchor.with_year(0).unwrap().with_month(1).unwrap())
+        );
+    }
+}
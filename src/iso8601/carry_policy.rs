@@ -0,0 +1,21 @@
+/// What to do when calendar arithmetic or a [DateTimeBuilder](crate::iso8601::DateTimeBuilder)
+/// gives a date or time that doesn't exist, like January 31 plus one month, or a second of 60
+/// in a minute without a leap second. The part that doesn't fit is called the carry, see
+/// [DateTimeWithCarry](crate::iso8601::DateTimeWithCarry).
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum CarryPolicy {
+    /// Drop the carry, so that the result is clamped to the last day of the month or the last
+    /// second of the day. January 31 plus one month is February 28 or 29.
+    Constrain,
+    /// Add the carry to the result, so that it overflows into the following days. January 31
+    /// plus one month is March 2 or 3.
+    Overflow,
+    /// Fail if there is any carry. This is what [DateTimeBuilder](crate::iso8601::DateTimeBuilder)
+    /// does by default.
+    #[default]
+    Reject,
+    /// Transfer as much of the carry to the result as fits without overflowing into the next
+    /// month or minute, and drop the rest. See
+    /// [DateTimeWithCarry::absorb_carry](crate::iso8601::DateTimeWithCarry::absorb_carry).
+    Absorb,
+}
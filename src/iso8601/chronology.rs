@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use zoneinfo_compiled::TZData;
@@ -33,6 +35,20 @@ impl PartialEq<Self> for Chronology {
 
 impl Eq for Chronology {}
 
+impl Hash for Chronology {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.pimpl).hash(state)
+    }
+}
+
+impl Chronology {
+    // An arbitrary but consistent order of chronologies that agrees with Eq, for breaking ties
+    // between otherwise equal values of different chronologies.
+    pub(crate) fn cmp_identity(&self, other: &Self) -> Ordering {
+        Arc::as_ptr(&self.pimpl).cmp(&Arc::as_ptr(&other.pimpl))
+    }
+}
+
 pub(super) struct SharedChronology {
     pub(crate) tz_data: TZData,
     leap_second_smearing: bool,
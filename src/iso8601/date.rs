@@ -0,0 +1,352 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::gregorian_normalized_date::GregorianNormalizedDate;
+use crate::iso8601::carry_policy::CarryPolicy;
+use crate::iso8601::chronology::Chronology;
+use crate::iso8601::date_time::{is_in_range, MAX_MONTHS_SPAN, MAX_YEARS_SPAN};
+use crate::iso8601::date_time_builder::Error;
+use crate::iso8601::parse_error::{parse_digits, ParseError};
+use crate::iso8601::precision::Precision;
+use crate::iso8601::util::is_leap_year;
+use crate::iso8601::{DateTime, Month, Time, Weekday, DAYS_PER_WEEK};
+
+/// An ISO 8601 calendar date without a time of day or a time zone, e.g. a birthday. The range is
+/// from 0000-01-01 to 9999-12-31, like [DateTime].
+///
+/// Dates are formatted and parsed in the ISO 8601 extended format, e.g. `2024-05-17`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Date(GregorianNormalizedDate);
+
+impl Date {
+    /// Returns the date with the given year, month from 1 to 12 and day of the month from 1.
+    pub fn from_ymd(year: u16, month: u8, day: u8) -> Result<Date, Error> {
+        if day == 0 {
+            return Err(Error::InvalidDateTime);
+        }
+        let gnd = GregorianNormalizedDate::from_date(year as i32, month, day)?;
+        if !is_in_range(&gnd) {
+            return Err(Error::DateTimeOutOfBounds);
+        }
+        Ok(Date(gnd))
+    }
+
+    pub(super) fn from_gnd(gnd: GregorianNormalizedDate) -> Date {
+        Date(gnd)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.0
+            .unnormalized_year()
+            .try_into()
+            .expect("year is within the range of u16")
+    }
+
+    pub fn month(&self) -> u8 {
+        self.0.unnormalized_month()
+    }
+
+    pub fn day(&self) -> u8 {
+        self.0.unnormalized_day()
+    }
+
+    /// The month as a [Month], see [Date::month].
+    pub fn month_of_year(&self) -> Month {
+        Month::from_number(self.month()).expect("month is in the range 1-12")
+    }
+
+    /// The quarter of the year, from 1 to 4.
+    pub fn quarter(&self) -> u8 {
+        self.month_of_year().quarter()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_fixed_day(self.0.to_day())
+    }
+
+    /// The ordinal day of the year, from 1 for January 1 to 365 or 366 for December 31.
+    pub fn day_of_year(&self) -> u16 {
+        let january_1 = GregorianNormalizedDate::from_date(self.year() as i32, 1, 1)
+            .expect("January 1 of a year of Date is a valid date");
+        (self.0.to_day() - january_1.to_day() + 1) as u16
+    }
+
+    /// The ISO 8601 week-numbering year and week of the year, from 1 to 52 or 53. Week 1 is the
+    /// week that contains the first Thursday of the year, so the first days of January can be
+    /// in the last week of the previous year, and the last days of December in week 1 of the
+    /// next year. The week-numbering year of 0000-01-01 is -1.
+    pub fn iso_week(&self) -> (i32, u8) {
+        let year = self.year() as i32;
+        // The week is the one of its Thursday, which is always in the week-numbering year.
+        let thursday = self.day_of_year() as i32 + Weekday::Thursday.number() as i32
+            - self.weekday().number() as i32;
+        let (year, thursday) = if thursday < 1 {
+            (year - 1, thursday + days_in_year(year - 1) as i32)
+        } else if thursday > days_in_year(year) as i32 {
+            (year + 1, thursday - days_in_year(year) as i32)
+        } else {
+            (year, thursday)
+        };
+        (year, ((thursday - 1) / DAYS_PER_WEEK as i32 + 1) as u8)
+    }
+
+    pub fn days_in_month(&self) -> u8 {
+        self.0.days_in_month()
+    }
+
+    pub fn days_in_year(&self) -> u16 {
+        days_in_year(self.year() as i32)
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year() as i128)
+    }
+
+    /// Returns the date the given number of days later, or earlier if negative. Returns None if
+    /// the result is out of range.
+    pub fn checked_add_days(&self, days: i32) -> Option<Date> {
+        let mut gnd = self.0.clone();
+        gnd.add_days(days).ok()?;
+        is_in_range(&gnd).then_some(Date(gnd))
+    }
+
+    /// Returns the date the given number of months later, or earlier if negative. A day that
+    /// doesn't exist in the resulting month is resolved by the policy, see [CarryPolicy].
+    /// [CarryPolicy::Absorb] is the same as [CarryPolicy::Constrain], since a Date has no time
+    /// of day to absorb the carry. Returns None if the result is out of range or rejected.
+    pub fn checked_add_months_with(&self, months: i32, policy: CarryPolicy) -> Option<Date> {
+        if months.unsigned_abs() > MAX_MONTHS_SPAN {
+            return None;
+        }
+        let mut gnd = self.0.clone();
+        let day_carry = gnd.add_months(months);
+        Date::resolve(gnd, day_carry as i32, policy)
+    }
+
+    /// Like [Date::checked_add_months_with], for years. Only February 29 can have a carry.
+    pub fn checked_add_years_with(&self, years: i16, policy: CarryPolicy) -> Option<Date> {
+        if years.unsigned_abs() > MAX_YEARS_SPAN {
+            return None;
+        }
+        let mut gnd = self.0.clone();
+        let day_carry = gnd.add_years(years);
+        Date::resolve(gnd, day_carry as i32, policy)
+    }
+
+    /// Returns the number of days from the other date to this one, which is negative if the
+    /// other date is later.
+    pub fn days_since(&self, other: &Date) -> i32 {
+        self.0.to_day() - other.0.to_day()
+    }
+
+    /// Returns the DateTime of the given time of day on this date in the given chronology, with
+    /// a precision of nanoseconds. Returns an error if the time is a leap second and the
+    /// chronology doesn't have one at the end of this date.
+    pub fn at(&self, time: &Time, chronology: &Chronology) -> Result<DateTime, Error> {
+        let day_length = chronology.leap_seconds().day_length(self.0.to_day());
+        if time.second_of_day() >= day_length {
+            return Err(Error::InvalidDateTime);
+        }
+        Ok(DateTime::new(
+            chronology.clone(),
+            Precision::Nanoseconds,
+            self.0.clone(),
+            time.second_of_day(),
+            time.subsecond_nanoseconds(),
+        ))
+    }
+
+    fn resolve(gnd: GregorianNormalizedDate, day_carry: i32, policy: CarryPolicy) -> Option<Date> {
+        if !is_in_range(&gnd) {
+            return None;
+        }
+        match policy {
+            CarryPolicy::Constrain | CarryPolicy::Absorb => Some(Date(gnd)),
+            CarryPolicy::Overflow => Date(gnd).checked_add_days(day_carry),
+            CarryPolicy::Reject => (day_carry == 0).then_some(Date(gnd)),
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year(),
+            self.month(),
+            self.day()
+        )
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseError;
+
+    /// Parses a date in the ISO 8601 extended format, `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = ParseError::InvalidFormat("YYYY-MM-DD");
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(format);
+        }
+        let year = parse_digits(&bytes[0..4]).ok_or(format)?;
+        let month = parse_digits(&bytes[5..7]).ok_or(format)?;
+        let day = parse_digits(&bytes[8..10]).ok_or(format)?;
+        Date::from_ymd(year as u16, month as u8, day as u8).map_err(|_| ParseError::InvalidValue)
+    }
+}
+
+fn days_in_year(year: i32) -> u16 {
+    if is_leap_year(year as i128) {
+        366
+    } else {
+        365
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::load_chronology;
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn from_ymd() {
+        let date = date(2024, 2, 29);
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+        assert_eq!(Date::from_ymd(2023, 2, 29), Err(Error::InvalidDateTime));
+        assert_eq!(Date::from_ymd(2023, 13, 1), Err(Error::InvalidDateTime));
+        assert_eq!(Date::from_ymd(2023, 1, 0), Err(Error::InvalidDateTime));
+        assert_eq!(Date::from_ymd(10000, 1, 1), Err(Error::DateTimeOutOfBounds));
+        assert!(Date::from_ymd(0, 1, 1).is_ok());
+        assert!(Date::from_ymd(9999, 12, 31).is_ok());
+        assert!(Date::from_ymd(2024, 5, 17).unwrap() < Date::from_ymd(2024, 5, 18).unwrap());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(
+            date(2024, 2, 28).checked_add_days(2),
+            Some(date(2024, 3, 1))
+        );
+        assert_eq!(
+            date(2024, 1, 1).checked_add_days(-1),
+            Some(date(2023, 12, 31))
+        );
+        assert_eq!(date(9999, 12, 31).checked_add_days(1), None);
+        assert_eq!(date(0, 1, 1).checked_add_days(-1), None);
+        assert_eq!(date(2024, 3, 1).days_since(&date(2024, 2, 1)), 29);
+        assert_eq!(date(2024, 2, 1).days_since(&date(2024, 3, 1)), -29);
+
+        let january_31 = date(2024, 1, 31);
+        for (policy, expected) in [
+            (CarryPolicy::Constrain, Some(date(2024, 2, 29))),
+            (CarryPolicy::Absorb, Some(date(2024, 2, 29))),
+            (CarryPolicy::Overflow, Some(date(2024, 3, 2))),
+            (CarryPolicy::Reject, None),
+        ] {
+            assert_eq!(
+                january_31.checked_add_months_with(1, policy),
+                expected,
+                "{:?}",
+                policy
+            );
+        }
+        assert_eq!(
+            january_31.checked_add_months_with(-2, CarryPolicy::Reject),
+            None
+        );
+        assert_eq!(
+            january_31.checked_add_months_with(2, CarryPolicy::Reject),
+            Some(date(2024, 3, 31))
+        );
+        assert_eq!(
+            date(2024, 2, 29).checked_add_years_with(1, CarryPolicy::Overflow),
+            Some(date(2025, 3, 1))
+        );
+        assert_eq!(
+            date(2024, 2, 29).checked_add_years_with(4, CarryPolicy::Reject),
+            Some(date(2028, 2, 29))
+        );
+        assert_eq!(
+            date(9999, 12, 1).checked_add_months_with(1, CarryPolicy::Constrain),
+            None
+        );
+
+        // Whole multiples of the 400-year cycle used to wrap around to the same date.
+        for policy in [CarryPolicy::Constrain, CarryPolicy::Reject] {
+            assert_eq!(january_31.checked_add_years_with(25_600, policy), None);
+            assert_eq!(january_31.checked_add_years_with(-25_600, policy), None);
+            assert_eq!(january_31.checked_add_months_with(786_432, policy), None);
+            assert_eq!(january_31.checked_add_months_with(-786_432, policy), None);
+        }
+        assert_eq!(
+            date(0, 1, 31).checked_add_years_with(9999, CarryPolicy::Reject),
+            Some(date(9999, 1, 31))
+        );
+        assert_eq!(
+            date(9999, 12, 31).checked_add_months_with(-9999 * 12 - 11, CarryPolicy::Reject),
+            Some(date(0, 1, 31))
+        );
+    }
+
+    #[test]
+    fn at() {
+        let chronology = load_chronology("UTC");
+        let time = Time::from_hms_nano(13, 45, 30, 123_456_789).unwrap();
+        let date_time = date(2024, 5, 17).at(&time, &chronology).unwrap();
+        assert_eq!(
+            date_time,
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(2024)
+                .month(5)
+                .day(17)
+                .hour(13)
+                .minute(45)
+                .second(30)
+                .millisecond(123)
+                .microsecond(456)
+                .nanosecond(789)
+                .build()
+        );
+        assert_eq!(date_time.date(), date(2024, 5, 17));
+        assert_eq!(date_time.time(), time);
+
+        let leap_second = Time::from_hms(23, 59, 60).unwrap();
+        let date_time = date(2016, 12, 31).at(&leap_second, &chronology).unwrap();
+        assert_eq!(date_time.second(), 60);
+        assert_eq!(date_time.time(), leap_second);
+        assert_eq!(
+            date(2016, 12, 30).at(&leap_second, &chronology),
+            Err(Error::InvalidDateTime)
+        );
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(date(2024, 5, 17).to_string(), "2024-05-17");
+        assert_eq!(date(7, 1, 2).to_string(), "0007-01-02");
+        assert_eq!("2024-05-17".parse(), Ok(date(2024, 5, 17)));
+        assert_eq!("0000-01-01".parse(), Ok(date(0, 1, 1)));
+        assert_eq!("2023-02-29".parse::<Date>(), Err(ParseError::InvalidValue));
+        for s in [
+            "2024-5-17",
+            "20240517",
+            "2024-05-17T00",
+            "2024/05/17",
+            "+024-05-17",
+        ] {
+            assert_eq!(
+                s.parse::<Date>(),
+                Err(ParseError::InvalidFormat("YYYY-MM-DD")),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -1,17 +1,32 @@
 use crate::div_rem::ClampedDivRem;
 use crate::duration::DurationS64;
 use crate::gregorian_normalized_date::GregorianNormalizedDate;
-use crate::instant::InstantS64;
+use crate::instant::{InstantS64, Tick};
+use crate::iso8601::carry_policy::CarryPolicy;
 use crate::iso8601::chronology::Chronology;
+use crate::iso8601::date_time_builder::Error;
 use crate::iso8601::precision::Precision;
 use crate::iso8601::{
-    DateTimeBuilder, HOURS_PER_DAY, MINUTES_PER_DAY, MINUTES_PER_HOUR, SECONDS_PER_DAY,
-    SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+    Date, DateTimeBuilder, Month, Time, Weekday, HOURS_PER_DAY, MINUTES_PER_DAY, MINUTES_PER_HOUR,
+    SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
 };
 use crate::zoneinfo::SegmentLookupResult;
+use crate::{Instant, InstantNs128, Scale};
 use num_integer::Integer;
-use std::cmp::min;
+use std::cmp::{max, min, Ordering};
 use std::fmt::{Debug, Formatter};
+use thiserror::Error;
+
+/// An error converting between a [DateTime] and an [Instant].
+#[derive(Error, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DateTimeConversionError {
+    /// The instant is outside the range of DateTime, from 0000-01-01 to 9999-12-31.
+    #[error("date and time is outside the range 0000-01-01 to 9999-12-31")]
+    DateTimeOutOfRange,
+    /// The date and time can't be represented in the tick type of the instant.
+    #[error("instant is out of range")]
+    InstantOutOfRange,
+}
 
 #[derive(Debug, Clone)]
 pub struct Carry {
@@ -31,6 +46,10 @@ impl Carry {
 pub struct DateTimeWithCarry(DateTime, Carry);
 
 impl DateTimeWithCarry {
+    pub(super) fn new(date_time: DateTime, days: u32, seconds: u64) -> Self {
+        DateTimeWithCarry(date_time, Carry { days, seconds })
+    }
+
     pub(super) fn with_days(date_time: DateTime, days: u32) -> Self {
         DateTimeWithCarry(date_time, Carry { days, seconds: 0 })
     }
@@ -69,14 +88,68 @@ impl DateTimeWithCarry {
     }
     pub fn checked_apply_carry(&self) -> Option<DateTime> {
         let carry = self.1.clone();
-        let DateTimeWithCarry(result, carry2) = self.0.add_days(carry.days as i32);
+        let DateTimeWithCarry(result, carry2) = self.0.checked_add_days(carry.days as i32)?;
         assert_eq!(carry2.days, 0);
         result.checked_add_seconds((carry.seconds + carry2.seconds) as i64)
     }
+
+    /// Transfers as much of the carry to the date and time as fits without overflowing into the
+    /// next component, and returns the rest as the new carry. Days of carry move the date up to
+    /// the last day of its month, and seconds of carry move the time up to the last second of
+    /// its minute. E.g. with a time of 12:30:58 and a carry of 2 seconds, the time becomes
+    /// 12:30:59 and the carry 1 second.
+    pub fn absorb_carry(&self) -> DateTimeWithCarry {
+        let DateTimeWithCarry(date_time, carry) = self;
+        let (precision, mut gnd, second, nanosecond) = DateTime::unpack0(date_time.w0);
+
+        let days = min(
+            carry.days,
+            (gnd.days_in_month() - gnd.unnormalized_day()) as u32,
+        );
+        gnd.add_days(days as i32)
+            .expect("the date stays within its month");
+        // The new day can be shorter if the old one had a leap second.
+        let (second, seconds_cut) = date_time.spill_eod_second_overflow(&gnd, second);
+        let seconds_carry = carry.seconds + seconds_cut as u64;
+
+        // Only the last minute of the day can have a leap second.
+        let boundary = SECONDS_PER_DAY - SECONDS_PER_MINUTE as u32;
+        let last_second = if second < boundary {
+            second - second % SECONDS_PER_MINUTE as u32 + SECONDS_PER_MINUTE as u32 - 1
+        } else {
+            date_time.chronology.leap_seconds().day_length(gnd.to_day()) - 1
+        };
+        let seconds = min(seconds_carry, (last_second - second) as u64);
+        let second = second + seconds as u32;
+        let result = DateTime {
+            w0: DateTime::pack0(precision, gnd, second, nanosecond),
+            w1: date_time.w1,
+            chronology: date_time.chronology.clone(),
+        };
+        DateTimeWithCarry::new(result, carry.days - days, seconds_carry - seconds)
+    }
+
+    /// Resolves the carry with the given policy. Returns None if the policy is
+    /// [CarryPolicy::Reject] and there is a carry, or if applying the carry with
+    /// [CarryPolicy::Overflow] goes out of range.
+    pub fn resolve(&self, policy: CarryPolicy) -> Option<DateTime> {
+        match policy {
+            CarryPolicy::Constrain => Some(self.0.clone()),
+            CarryPolicy::Overflow => self.checked_apply_carry(),
+            CarryPolicy::Reject => (!self.has_carry()).then(|| self.0.clone()),
+            CarryPolicy::Absorb => Some(self.absorb_carry().drop_carry()),
+        }
+    }
 }
 
 /// An ISO 8601 date and time. The range is from 0000-01-01 to 9999-12-31.
-#[derive(Clone, Eq, PartialEq)]
+///
+/// DateTimes are equal if they have the same date and time, precision and chronology. They are
+/// ordered by date and time, which within a chronology is the same as ordering by instant. Ties
+/// are broken by precision and then by an arbitrary but consistent order of chronologies, so that
+/// the ordering agrees with equality. To compare DateTimes of different chronologies by the
+/// instants they represent, use [DateTime::cmp_instant].
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct DateTime {
     // - P Precision: 13, 4 bits
     // - E cycle: 25 [-1-23], 5 bits
@@ -106,6 +179,25 @@ impl Debug for DateTime {
     }
 }
 
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (precision, gnd, second, nanosecond) = Self::unpack(self.w0, self.w1);
+        let (other_precision, other_gnd, other_second, other_nanosecond) =
+            Self::unpack(other.w0, other.w1);
+        gnd.cmp(&other_gnd)
+            .then(second.cmp(&other_second))
+            .then(nanosecond.cmp(&other_nanosecond))
+            .then(precision.cmp(&other_precision))
+            .then_with(|| self.chronology.cmp_identity(&other.chronology))
+    }
+}
+
 // The maximum fixed day in the range of DateTime. The maximum fixed day occurs on 9999-12-31
 // so this is the number of days between 1970-01-01 and 9999-12-31.
 const MAX_FIXED_DAY: u32 = 2932896;
@@ -114,9 +206,18 @@ const MAX_FIXED_DAY: u32 = 2932896;
 // so this is the number of days between 1970-01-01 and 0000-01-01.
 const MIN_FIXED_DAY: i32 = -719528;
 
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+const NANOSECONDS_PER_MILLISECOND: u32 = 1_000_000;
+const NANOSECONDS_PER_MICROSECOND: u32 = 1_000;
+
 const MIN_YEAR: u16 = 0;
 const MAX_YEAR: u16 = 9999;
 
+// The most years and months between two DateTimes. Adding more is out of range whatever the
+// DateTime, and is rejected before the arithmetic of the GregorianNormalizedDate overflows.
+pub(super) const MAX_YEARS_SPAN: u16 = MAX_YEAR - MIN_YEAR;
+pub(super) const MAX_MONTHS_SPAN: u32 = MAX_YEARS_SPAN as u32 * 12 + 11;
+
 // The minimum of the DateTime represented as a GregorianNormalizedDate. The
 // date 0000-03-01 is exactly 2000/400 = 5 cycles before the GND epoch. However,
 // we want to represent the date 0000-01-01 which is two months before, so we
@@ -176,9 +277,9 @@ impl DateTime {
         let year = gnd.year;
         let day = gnd.day;
         let rebased_cycle = cycle as i32 + 6;
-        ((nanosecond & 0x7FFF0000) as u64) << 48
+        (((nanosecond >> 16) & 0x3FFF) as u64) << 48
             | (p as u64) << 41
-            | ((second & 0x10000) as u64) << 40
+            | (((second >> 16) & 0x1) as u64) << 40
             | ((rebased_cycle & 0x1F) as u64) << 35
             | ((century & 0x3) as u64) << 33
             | ((quadrennium & 0x1F) as u64) << 27
@@ -194,9 +295,9 @@ impl DateTime {
         let quadrennium = ((w0 >> 27) & 0x1F) as u8;
         let century = ((w0 >> 33) & 0x3) as u8;
         let cycle = ((w0 >> 35) & 0x1F) as i8 - 6;
-        let second = ((w0 >> 40) & 0x10000) as u32 | lower_second;
-        let precision = Self::decode_precision((w0 >> 41) as u8);
-        let nanosecond = ((w0 >> 48) & 0x7FFF0000) as u32;
+        let second = (((w0 >> 40) & 0x1) << 16) as u32 | lower_second;
+        let precision = Self::decode_precision(((w0 >> 41) & 0xF) as u8);
+        let nanosecond = (((w0 >> 48) & 0x3FFF) << 16) as u32;
         let gnd = GregorianNormalizedDate {
             cycle,
             century,
@@ -277,6 +378,54 @@ impl DateTime {
         gnd.unnormalized_day()
     }
 
+    /// The date without the time of day.
+    pub fn date(&self) -> Date {
+        let (_, gnd, _, _) = Self::unpack0(self.w0);
+        Date::from_gnd(gnd)
+    }
+
+    /// The time of day without the date, which is 23:59:60 during a leap second.
+    pub fn time(&self) -> Time {
+        let (_, _, second, nanosecond) = Self::unpack(self.w0, self.w1);
+        Time::from_second_of_day(second, nanosecond)
+    }
+
+    /// The month as a [Month], see [DateTime::month].
+    pub fn month_of_year(&self) -> Month {
+        self.date().month_of_year()
+    }
+
+    /// The quarter of the year, from 1 to 4.
+    pub fn quarter(&self) -> u8 {
+        self.date().quarter()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        self.date().weekday()
+    }
+
+    /// The ordinal day of the year, see [Date::day_of_year].
+    pub fn day_of_year(&self) -> u16 {
+        self.date().day_of_year()
+    }
+
+    /// The ISO 8601 week-numbering year and week of the year, see [Date::iso_week].
+    pub fn iso_week(&self) -> (i32, u8) {
+        self.date().iso_week()
+    }
+
+    pub fn days_in_month(&self) -> u8 {
+        self.date().days_in_month()
+    }
+
+    pub fn days_in_year(&self) -> u16 {
+        self.date().days_in_year()
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        self.date().is_leap_year()
+    }
+
     pub fn hour(&self) -> u8 {
         let (_, _, second, _) = Self::unpack0(self.w0);
         let hour = (second / SECONDS_PER_HOUR as u32) as u8;
@@ -306,13 +455,86 @@ impl DateTime {
         }
     }
 
-    // TODO function to transfer as much carry as possible to datetime without
-    // overflowing to the next component. E.g. with a second of 58 and a carry of
-    // 2, the carry should be reduced to 1 and the second increased to 59.
-    // I *think* there's a use case for this but that would need to be figured
-    // out as well. Something about chaining multiple operations without
-    // "falling behind" the actual time. It might matter when you're e.g.
-    // adding a month to something that has fallen behind? I'm not sure.
+    pub fn millisecond(&self) -> u16 {
+        let (_, _, _, nanosecond) = Self::unpack(self.w0, self.w1);
+        (nanosecond / NANOSECONDS_PER_MILLISECOND) as u16
+    }
+
+    /// The microsecond of the millisecond, like [DateTimeBuilder::microsecond].
+    pub fn microsecond(&self) -> u16 {
+        let (_, _, _, nanosecond) = Self::unpack(self.w0, self.w1);
+        (nanosecond / NANOSECONDS_PER_MICROSECOND % 1_000) as u16
+    }
+
+    /// The nanosecond of the microsecond, like [DateTimeBuilder::nanosecond].
+    pub fn nanosecond(&self) -> u16 {
+        let (_, _, _, nanosecond) = Self::unpack(self.w0, self.w1);
+        (nanosecond % NANOSECONDS_PER_MICROSECOND) as u16
+    }
+
+    pub fn precision(&self) -> Precision {
+        let (precision, _, _, _) = Self::unpack0(self.w0);
+        precision
+    }
+
+    // The with_* methods rebuild the DateTime with one component replaced, so they validate the
+    // result exactly like DateTimeBuilder does. E.g. changing the day of 2016-12-31 23:59:60 fails
+    // because the other days of that month don't have a leap second. Replacing a component finer
+    // than the precision of the DateTime increases the precision.
+
+    pub fn with_year(&self, year: u16) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .year(year)
+            .checked_build()
+    }
+
+    pub fn with_month(&self, month: u8) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .month(month)
+            .checked_build()
+    }
+
+    pub fn with_day(&self, day: u8) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .day(day)
+            .checked_build()
+    }
+
+    pub fn with_hour(&self, hour: u8) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .hour(hour)
+            .checked_build()
+    }
+
+    pub fn with_minute(&self, minute: u8) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .minute(minute)
+            .checked_build()
+    }
+
+    pub fn with_second(&self, second: u8) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .second(second)
+            .checked_build()
+    }
+
+    pub fn with_millisecond(&self, millisecond: u16) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .millisecond(millisecond)
+            .checked_build()
+    }
+
+    pub fn with_microsecond(&self, microsecond: u16) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .microsecond(microsecond)
+            .checked_build()
+    }
+
+    pub fn with_nanosecond(&self, nanosecond: u16) -> Result<Self, Error> {
+        DateTimeBuilder::from_date_time(self)
+            .nanosecond(nanosecond)
+            .checked_build()
+    }
 
     pub fn add_years(&self, years: i16) -> DateTimeWithCarry {
         self.checked_add_years(years)
@@ -320,6 +542,9 @@ impl DateTime {
     }
 
     pub fn checked_add_years(&self, years: i16) -> Option<DateTimeWithCarry> {
+        if years.unsigned_abs() > MAX_YEARS_SPAN {
+            return None;
+        }
         let (precision, mut gnd, second, nanosecond) = Self::unpack0(self.w0);
         let day_carry = gnd.add_years(years);
         if !is_in_range(&gnd) {
@@ -341,12 +566,20 @@ impl DateTime {
         ))
     }
 
+    /// Like [DateTime::checked_add_years], with the carry resolved by the given policy.
+    pub fn checked_add_years_with(&self, years: i16, policy: CarryPolicy) -> Option<Self> {
+        self.checked_add_years(years)?.resolve(policy)
+    }
+
     pub fn add_months(&self, months: i32) -> DateTimeWithCarry {
         self.checked_add_months(months)
             .expect("adding months to a DateTime resulted in an out-of-bounds value")
     }
 
     pub fn checked_add_months(&self, months: i32) -> Option<DateTimeWithCarry> {
+        if months.unsigned_abs() > MAX_MONTHS_SPAN {
+            return None;
+        }
         let (precision, mut gnd, second, nanosecond) = Self::unpack0(self.w0);
         let day_carry = gnd.add_months(months);
         if !is_in_range(&gnd) {
@@ -367,6 +600,11 @@ impl DateTime {
         ))
     }
 
+    /// Like [DateTime::checked_add_months], with the carry resolved by the given policy.
+    pub fn checked_add_months_with(&self, months: i32, policy: CarryPolicy) -> Option<Self> {
+        self.checked_add_months(months)?.resolve(policy)
+    }
+
     pub fn add_days(&self, days: i32) -> DateTimeWithCarry {
         self.checked_add_days(days)
             .expect("adding days to a DateTime resulted in an out-of-bounds value")
@@ -393,6 +631,11 @@ impl DateTime {
         ))
     }
 
+    /// Like [DateTime::checked_add_days], with the carry resolved by the given policy.
+    pub fn checked_add_days_with(&self, days: i32, policy: CarryPolicy) -> Option<Self> {
+        self.checked_add_days(days)?.resolve(policy)
+    }
+
     pub fn add_hours(&self, hours: i32) -> DateTimeWithCarry {
         self.checked_add_hours(hours)
             .expect("adding hours to a DateTime resulted in an out-of-bounds value")
@@ -402,6 +645,11 @@ impl DateTime {
         self.checked_add_minutes(hours as i64 * MINUTES_PER_HOUR as i64)
     }
 
+    /// Like [DateTime::checked_add_hours], with the carry resolved by the given policy.
+    pub fn checked_add_hours_with(&self, hours: i32, policy: CarryPolicy) -> Option<Self> {
+        self.checked_add_hours(hours)?.resolve(policy)
+    }
+
     pub fn add_minutes(&self, minutes: i64) -> DateTimeWithCarry {
         self.checked_add_minutes(minutes)
             .expect("adding minutes to a DateTime resulted in an out-of-bounds value")
@@ -460,6 +708,11 @@ impl DateTime {
         ))
     }
 
+    /// Like [DateTime::checked_add_minutes], with the carry resolved by the given policy.
+    pub fn checked_add_minutes_with(&self, minutes: i64, policy: CarryPolicy) -> Option<Self> {
+        self.checked_add_minutes(minutes)?.resolve(policy)
+    }
+
     pub fn add_seconds(&self, seconds: i64) -> Self {
         self.checked_add_seconds(seconds)
             .expect("adding seconds to a DateTime resulted in overflow")
@@ -478,8 +731,63 @@ impl DateTime {
         })
     }
 
+    pub fn add_milliseconds(&self, milliseconds: i64) -> Self {
+        self.checked_add_milliseconds(milliseconds)
+            .expect("adding milliseconds to a DateTime resulted in overflow")
+    }
+
+    /// Adds milliseconds, carrying into the seconds like [DateTime::checked_add_seconds], so leap
+    /// seconds are counted. If the milliseconds aren't whole seconds, the precision is raised to
+    /// milliseconds when it is coarser, so that the result doesn't hide a part it can't show.
+    pub fn checked_add_milliseconds(&self, milliseconds: i64) -> Option<Self> {
+        let (seconds, millisecond) = milliseconds.div_mod_floor(&1_000);
+        self.checked_add_seconds_and_nanoseconds(
+            seconds,
+            millisecond as u32 * NANOSECONDS_PER_MILLISECOND,
+        )
+    }
+
+    pub fn add_nanoseconds(&self, nanoseconds: i64) -> Self {
+        self.checked_add_nanoseconds(nanoseconds)
+            .expect("adding nanoseconds to a DateTime resulted in overflow")
+    }
+
+    /// Adds nanoseconds, carrying into the seconds like [DateTime::checked_add_seconds], so leap
+    /// seconds are counted. If the nanoseconds aren't whole seconds, the precision is raised to
+    /// the finest of milliseconds, microseconds and nanoseconds that the added part needs, like
+    /// [DateTime::checked_add_milliseconds].
+    pub fn checked_add_nanoseconds(&self, nanoseconds: i64) -> Option<Self> {
+        let (seconds, nanosecond) = nanoseconds.div_mod_floor(&(NANOSECONDS_PER_SECOND as i64));
+        self.checked_add_seconds_and_nanoseconds(seconds, nanosecond as u32)
+    }
+
+    // Adds the seconds and the nanoseconds, which must be less than a second. The precision is
+    // raised to the finest subsecond unit of the nanoseconds if it is coarser.
+    pub(super) fn checked_add_seconds_and_nanoseconds(
+        &self,
+        seconds: i64,
+        nanoseconds: u32,
+    ) -> Option<Self> {
+        let (_, _, _, nanosecond) = Self::unpack(self.w0, self.w1);
+        let (carry, nanosecond) = (nanosecond + nanoseconds).div_rem(&NANOSECONDS_PER_SECOND);
+        let result = self.checked_add_seconds(seconds.checked_add(carry as i64)?)?;
+        let (precision, gnd, second, _) = Self::unpack(result.w0, result.w1);
+        let added_precision = match (
+            nanoseconds % NANOSECONDS_PER_MILLISECOND,
+            nanoseconds % NANOSECONDS_PER_MICROSECOND,
+        ) {
+            _ if nanoseconds == 0 => precision,
+            (0, _) => Precision::Milliseconds,
+            (_, 0) => Precision::Microseconds,
+            _ => Precision::Nanoseconds,
+        };
+        let precision = max(precision, added_precision);
+        let (w0, w1) = Self::pack(precision, gnd, second, nanosecond);
+        Some(DateTime { w0, w1, ..result })
+    }
+
     #[allow(clippy::wrong_self_convention)]
-    fn to_second_instant(&self, gnd: GregorianNormalizedDate, second: u32) -> InstantS64 {
+    pub(super) fn to_second_instant(&self, gnd: GregorianNormalizedDate, second: u32) -> InstantS64 {
         let leap_second_chronology = self.chronology.leap_seconds();
         let day = gnd.to_day();
         let seconds_since_epoch = match leap_second_chronology.by_day(day) {
@@ -535,8 +843,7 @@ impl DateTime {
                 // However, it is likely that the International Telecommunication Union (ITU) will
                 // decide to abolish leap seconds in the future. In fact no leap second have been added
                 // since 2016. So we might not need to worry about this.
-                let seconds_past_segment =
-                    (instant - last_segment.end_instant().into()).ticks() as u64;
+                let seconds_past_segment = (instant - last_segment.end_instant()).ticks() as u64;
                 let (days, second) = seconds_past_segment.div_rem(&(SECONDS_PER_DAY as u64));
                 let second = second as u32;
                 let fixed_day = (last_segment.end_day() as u64).checked_add(days)?;
@@ -549,7 +856,7 @@ impl DateTime {
                 Some((gnd, second))
             }
             SegmentLookupResult::In(segment) => {
-                let seconds_into_segment = (instant - segment.start_instant.into()).ticks() as u64;
+                let seconds_into_segment = (instant - segment.start_instant).ticks() as u64;
                 let (days, second) = seconds_into_segment.div_rem(&(SECONDS_PER_DAY as u64));
                 let (mut days, mut second) = (days as u32, second as u32);
                 let max_day = segment.duration_days - 1;
@@ -575,7 +882,7 @@ impl DateTime {
             }
             SegmentLookupResult::BeforeFirst(first_segment) => {
                 let seconds_until_first_segment =
-                    (first_segment.start_instant.into() - instant).ticks() as u64;
+                    (first_segment.start_instant - instant).ticks() as u64;
                 // When seconds_until_first_segment is 1, we want to end up subtracting 1 day and
                 // setting the second to 86399. Simply dividing by SECONDS_PER_DAY will give us 0 days.
                 // It's essentially a division that rounds up.
@@ -597,38 +904,158 @@ impl DateTime {
         }
     }
 
+    pub(super) fn chronology(&self) -> &Chronology {
+        &self.chronology
+    }
+
+    // Returns the number of days since the Unix epoch, the second of the day and the nanosecond
+    // of the second.
+    pub(super) fn to_fixed_day(&self) -> (i32, u32, u32) {
+        let (_, gnd, second, nanosecond) = Self::unpack(self.w0, self.w1);
+        (gnd.to_day(), second, nanosecond)
+    }
+
+    // Creates a DateTime from a number of days since the Unix epoch, the second of the day and
+    // the nanosecond of the second. Returns None if the day is outside the range of DateTime.
+    // The second must be within the length of the day.
+    pub(super) fn from_fixed_day(
+        chronology: &Chronology,
+        precision: Precision,
+        day: i32,
+        second: u32,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        if !(MIN_FIXED_DAY..=MAX_FIXED_DAY as i32).contains(&day) {
+            return None;
+        }
+        debug_assert!(second < chronology.leap_seconds().day_length(day));
+        let gnd = GregorianNormalizedDate::from_day(day)?;
+        Some(Self::new(
+            chronology.clone(),
+            precision,
+            gnd,
+            second,
+            nanosecond,
+        ))
+    }
+
+    /// Returns the instant of this date and time, including the nanosecond component. Any part
+    /// of the nanosecond component that can't be represented in the scale of the instant is
+    /// truncated. Leap seconds are counted as in the chronology of the date and time.
+    pub fn to_instant<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, DateTimeConversionError> {
+        let (_, gnd, second, nanosecond) = Self::unpack(self.w0, self.w1);
+        let seconds = self.to_second_instant(gnd, second).ticks_since_epoch();
+        Instant::from_seconds_and_nanoseconds(seconds, nanosecond)
+            .ok_or(DateTimeConversionError::InstantOutOfRange)
+    }
+
+    /// Returns the date and time of the given instant in the given chronology, including the
+    /// subsecond part of the instant, which is rounded down to nanoseconds. The precision of the
+    /// result follows the scale of the instant. An instant during a leap second gives a second of
+    /// 60.
+    pub fn from_instant<T: Tick, S: Scale>(
+        instant: Instant<T, S>,
+        chronology: &Chronology,
+    ) -> Result<Self, DateTimeConversionError> {
+        let (seconds, nanosecond) = instant
+            .to_seconds_and_nanoseconds()
+            .ok_or(DateTimeConversionError::DateTimeOutOfRange)?;
+        let seconds = InstantS64::from_ticks_since_epoch(seconds);
+        let ticks_per_second = S::ticks_per_second().ceil().to_integer();
+        let precision = match ticks_per_second {
+            1 => Precision::Seconds,
+            2..=1_000 => Precision::Milliseconds,
+            1_001..=1_000_000 => Precision::Microseconds,
+            _ => Precision::Nanoseconds,
+        };
+        let (gnd, second) = Self::from_second_instant(seconds, chronology)
+            .ok_or(DateTimeConversionError::DateTimeOutOfRange)?;
+        Ok(Self::new(
+            chronology.clone(),
+            precision,
+            gnd,
+            second,
+            nanosecond,
+        ))
+    }
+
+    /// Compares the instants of two DateTimes, which may be in different chronologies. Unlike
+    /// [Ord::cmp], DateTimes of different precision are equal if they are at the same instant.
+    pub fn cmp_instant(&self, other: &DateTime) -> Ordering {
+        self.instant()
+            .ticks_since_epoch()
+            .cmp(&other.instant().ticks_since_epoch())
+    }
+
+    pub(super) fn instant(&self) -> InstantNs128 {
+        self.to_instant()
+            .expect("every DateTime is within the range of InstantNs128")
+    }
+
+    // Clamps the second of the day to the last second of the given day, which can be shorter
+    // than the day the second was taken from because of leap seconds. Returns the clamped second
+    // and the number of seconds that were cut off.
     fn spill_eod_second_overflow(&self, gnd: &GregorianNormalizedDate, second: u32) -> (u32, u32) {
-        // TODO can the seconds overflow become extremely large, like thousands of years? If so
-        // we need a larger return type here, and probably some kind of fix to the logic.
-        let leap_second_chronology = self.chronology.leap_seconds();
-        let days_from_epoch = gnd.to_day();
-        if let SegmentLookupResult::In(segment) = leap_second_chronology.by_day(days_from_epoch) {
-            let day_offset = (days_from_epoch - segment.start_day as i32) as u32;
-            let leap_seconds = if day_offset == segment.duration_days {
-                segment.leap_seconds
-            } else {
-                0
-            };
-            let day_length_s = (SECONDS_PER_DAY as i32 + leap_seconds as i32) as u32;
-            if second >= day_length_s {
-                let second_carry = second - day_length_s;
-                (day_length_s, second_carry)
-            } else {
-                (second, 0)
-            }
+        let day_length = self.chronology.leap_seconds().day_length(gnd.to_day());
+        if second >= day_length {
+            let last_second = day_length - 1;
+            (last_second, second - last_second)
         } else {
             (second, 0)
         }
     }
 }
 
-fn is_in_range(gnd: &GregorianNormalizedDate) -> bool {
+pub(super) fn is_in_range(gnd: &GregorianNormalizedDate) -> bool {
     (MIN_GND..=MAX_GND).contains(gnd)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::iso8601::load_chronology;
+    use crate::scale::{Milliseconds, Seconds};
+
+    #[test]
+    fn pack_and_unpack() {
+        // The top bits of the second and nanosecond are stored apart from the rest, next to the
+        // precision, so round trip values that use them with every precision.
+        let precisions = [
+            Precision::Millennia,
+            Precision::Centuries,
+            Precision::Decades,
+            Precision::Years,
+            Precision::Months,
+            Precision::Weeks,
+            Precision::Days,
+            Precision::Hours,
+            Precision::Minutes,
+            Precision::Seconds,
+            Precision::Milliseconds,
+            Precision::Microseconds,
+            Precision::Nanoseconds,
+        ];
+        for precision in precisions {
+            for (gnd, second, nanosecond) in [
+                (MIN_GND, 0, 0),
+                (MAX_GND, SECONDS_PER_DAY, 999_999_999),
+                (MIN_GND, 65_535, 65_535),
+                (MAX_GND, 65_536, 65_536),
+                (MIN_GND, 86_399, 0x3FFF_0000),
+            ] {
+                let (w0, w1) = DateTime::pack(precision, gnd.clone(), second, nanosecond);
+                assert_eq!(
+                    DateTime::unpack(w0, w1),
+                    (precision, gnd.clone(), second, nanosecond)
+                );
+                let (unpacked_precision, unpacked_gnd, unpacked_second, _) = DateTime::unpack0(w0);
+                assert_eq!(
+                    (unpacked_precision, unpacked_gnd, unpacked_second),
+                    (precision, gnd, second)
+                );
+            }
+        }
+    }
 
     #[test]
     fn test_get_minute() {
@@ -1947,4 +2374,620 @@ mod tests {
             .build();
         assert!(date_time.checked_add_seconds(-315_569_520_027).is_none());
     }
+
+    #[test]
+    fn to_instant_and_back() {
+        let date_time = DateTime::builder()
+            .year(2023)
+            .month(7)
+            .day(14)
+            .hour(12)
+            .minute(30)
+            .second(15)
+            .millisecond(123)
+            .microsecond(456)
+            .nanosecond(789)
+            .build();
+        let instant: InstantNs128 = date_time.to_instant().unwrap();
+        assert_eq!(
+            instant.ticks_since_epoch(),
+            (1_689_337_815 + 27) * 1_000_000_000 + 123_456_789
+        );
+        assert_eq!(
+            DateTime::from_instant(instant, date_time.chronology()).unwrap(),
+            date_time
+        );
+
+        // Coarser scales truncate the nanosecond component.
+        let millis: Instant<i64, Milliseconds> = date_time.to_instant().unwrap();
+        assert_eq!(
+            millis.ticks_since_epoch(),
+            (1_689_337_815 + 27) * 1_000 + 123
+        );
+        let date_time = DateTime::from_instant(millis, date_time.chronology()).unwrap();
+        assert_eq!(date_time.second(), 15);
+        assert_eq!(date_time.to_fixed_day().2, 123_000_000);
+    }
+
+    #[test]
+    fn to_instant_and_back_during_leap_second() {
+        let date_time = DateTime::builder()
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .millisecond(500)
+            .microsecond(0)
+            .nanosecond(1)
+            .build();
+        let instant: InstantNs128 = date_time.to_instant().unwrap();
+        assert_eq!(
+            instant.ticks_since_epoch(),
+            (1_483_228_800 + 26) * 1_000_000_000 + 500_000_001
+        );
+        let round_trip = DateTime::from_instant(instant, date_time.chronology()).unwrap();
+        assert_eq!(round_trip, date_time);
+        assert_eq!(round_trip.second(), 60);
+    }
+
+    #[test]
+    fn to_instant_and_back_bounds_checking() {
+        let chronology = load_chronology("UTC");
+        let first = DateTime::builder()
+            .chronology(&chronology)
+            .year(0)
+            .month(1)
+            .day(1)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .build();
+        let instant: InstantS64 = first.to_instant().unwrap();
+        assert_eq!(DateTime::from_instant(instant, &chronology).unwrap(), first);
+        assert_eq!(
+            DateTime::from_instant(instant - DurationS64::new(1), &chronology),
+            Err(DateTimeConversionError::DateTimeOutOfRange)
+        );
+
+        let last = DateTime::builder()
+            .chronology(&chronology)
+            .year(9999)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(59)
+            .build();
+        let instant: InstantS64 = last.to_instant().unwrap();
+        assert_eq!(DateTime::from_instant(instant, &chronology).unwrap(), last);
+        assert_eq!(
+            DateTime::from_instant(instant + DurationS64::new(1), &chronology),
+            Err(DateTimeConversionError::DateTimeOutOfRange)
+        );
+        assert_eq!(
+            DateTime::from_instant(
+                Instant::<i128, Seconds>::from_ticks_since_epoch(i128::MAX),
+                &chronology
+            ),
+            Err(DateTimeConversionError::DateTimeOutOfRange)
+        );
+
+        assert_eq!(
+            last.to_instant::<i32, Seconds>(),
+            Err(DateTimeConversionError::InstantOutOfRange)
+        );
+    }
+
+    #[test]
+    fn subsecond_accessors() {
+        let date_time = DateTime::builder()
+            .year(2023)
+            .month(7)
+            .day(14)
+            .hour(12)
+            .minute(30)
+            .second(15)
+            .millisecond(123)
+            .microsecond(456)
+            .nanosecond(789)
+            .build();
+        assert_eq!(date_time.millisecond(), 123);
+        assert_eq!(date_time.microsecond(), 456);
+        assert_eq!(date_time.nanosecond(), 789);
+        assert_eq!(date_time.precision(), Precision::Nanoseconds);
+
+        let date_time = DateTime::builder()
+            .year(2023)
+            .month(7)
+            .day(14)
+            .hour(12)
+            .minute(30)
+            .second(15)
+            .build();
+        assert_eq!(date_time.millisecond(), 0);
+        assert_eq!(date_time.nanosecond(), 0);
+        assert_eq!(date_time.precision(), Precision::Seconds);
+    }
+
+    #[test]
+    fn with_components() {
+        let chronology = load_chronology("UTC");
+        let date_time = DateTime::builder()
+            .chronology(&chronology)
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .build();
+        let expected = |year, month, day, hour, minute, second| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(hour)
+                .minute(minute)
+                .second(second)
+                .build()
+        };
+        assert_eq!(
+            date_time.with_second(30).unwrap(),
+            expected(2016, 12, 31, 23, 59, 30)
+        );
+        assert_eq!(
+            date_time.with_second(59).unwrap().with_year(2017).unwrap(),
+            expected(2017, 12, 31, 23, 59, 59)
+        );
+        // Only the last minute of 2016-12-31 has a leap second.
+        assert_eq!(date_time.with_day(30), Err(Error::InvalidDateTime));
+        assert_eq!(date_time.with_year(2017), Err(Error::InvalidDateTime));
+        assert_eq!(date_time.with_minute(58), Err(Error::InvalidDateTime));
+        assert_eq!(date_time.with_hour(24), Err(Error::InvalidDateTime));
+        assert_eq!(date_time.with_year(10000), Err(Error::DateTimeOutOfBounds));
+
+        let date_time = expected(2024, 1, 31, 12, 0, 0);
+        assert_eq!(date_time.with_month(2), Err(Error::InvalidDateTime));
+        assert_eq!(
+            date_time.with_day(29).unwrap().with_month(2).unwrap(),
+            expected(2024, 2, 29, 12, 0, 0)
+        );
+        assert_eq!(
+            date_time.with_hour(0).unwrap().with_minute(1).unwrap(),
+            expected(2024, 1, 31, 0, 1, 0)
+        );
+
+        // Coarse precisions, as from with_precision, keep the components they don't include.
+        let month = date_time.with_precision(Precision::Months).unwrap();
+        let with_year = month.with_year(2025).unwrap();
+        assert_eq!(with_year.precision(), Precision::Months);
+        assert_eq!(
+            with_year,
+            expected(2025, 1, 1, 0, 0, 0)
+                .with_precision(Precision::Months)
+                .unwrap()
+        );
+        assert_eq!(
+            month.with_month(3).unwrap(),
+            expected(2024, 3, 1, 0, 0, 0)
+                .with_precision(Precision::Months)
+                .unwrap()
+        );
+        let with_day = month.with_day(15).unwrap();
+        assert_eq!(with_day.precision(), Precision::Days);
+        assert_eq!(with_day.day(), 15);
+        let year = date_time.with_precision(Precision::Years).unwrap();
+        assert_eq!(
+            year.with_year(2030).unwrap(),
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(2030)
+                .build()
+        );
+        let century = date_time.with_precision(Precision::Centuries).unwrap();
+        assert_eq!(century.with_year(1900).unwrap().year(), 1900);
+        assert_eq!(century.with_year(10000), Err(Error::DateTimeOutOfBounds));
+        // 2024-01-31 is in the week from Monday 2024-01-29.
+        let with_hour = date_time
+            .with_precision(Precision::Weeks)
+            .unwrap()
+            .with_hour(6)
+            .unwrap();
+        assert_eq!(
+            with_hour,
+            expected(2024, 1, 29, 6, 0, 0)
+                .with_precision(Precision::Hours)
+                .unwrap()
+        );
+
+        // Setting a subsecond component increases the precision.
+        let with_millisecond = date_time.with_millisecond(250).unwrap();
+        assert_eq!(with_millisecond.precision(), Precision::Milliseconds);
+        assert_eq!(with_millisecond.millisecond(), 250);
+        let with_nanosecond = with_millisecond.with_nanosecond(7).unwrap();
+        assert_eq!(with_nanosecond.precision(), Precision::Nanoseconds);
+        assert_eq!(with_nanosecond.millisecond(), 250);
+        assert_eq!(with_nanosecond.nanosecond(), 7);
+        assert_eq!(
+            with_nanosecond.with_microsecond(999).unwrap().microsecond(),
+            999
+        );
+        assert_eq!(
+            with_nanosecond.with_microsecond(1_000),
+            Err(Error::InvalidDateTime)
+        );
+    }
+
+    #[test]
+    fn add_subseconds() {
+        let chronology = load_chronology("UTC");
+        let date_time = DateTime::builder()
+            .chronology(&chronology)
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(59)
+            .millisecond(750)
+            .build();
+
+        // Into the leap second and out of it again.
+        let leap_second = date_time.add_milliseconds(500);
+        assert_eq!(leap_second.second(), 60);
+        assert_eq!(leap_second.millisecond(), 250);
+        let next_year = leap_second.add_nanoseconds(750_000_000);
+        assert_eq!(
+            (next_year.year(), next_year.month(), next_year.day()),
+            (2017, 1, 1)
+        );
+        assert_eq!((next_year.hour(), next_year.second()), (0, 0));
+        assert_eq!(next_year.millisecond(), 0);
+        assert_eq!(next_year.precision(), Precision::Milliseconds);
+        assert_eq!(next_year.add_milliseconds(-1_250), date_time);
+        assert_eq!(next_year.add_nanoseconds(-1_250_000_000), date_time);
+
+        let date_time = date_time.add_nanoseconds(-750_000_001);
+        assert_eq!(date_time.second(), 58);
+        assert_eq!(date_time.millisecond(), 999);
+        assert_eq!(date_time.microsecond(), 999);
+        assert_eq!(date_time.nanosecond(), 999);
+
+        let last = DateTime::builder()
+            .year(9999)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(59)
+            .millisecond(999)
+            .build();
+        assert!(last.checked_add_nanoseconds(999_999).is_some());
+        assert!(last.checked_add_milliseconds(1).is_none());
+        assert!(last.checked_add_nanoseconds(i64::MIN).is_some());
+
+        // The precision is raised to show the part that was added, but never lowered.
+        let seconds = DateTime::builder()
+            .chronology(&chronology)
+            .year(2024)
+            .month(1)
+            .day(1)
+            .hour(0)
+            .minute(0)
+            .second(30)
+            .build();
+        assert_eq!(seconds.precision(), Precision::Seconds);
+        let raised = seconds.add_milliseconds(500);
+        assert_eq!(raised.precision(), Precision::Milliseconds);
+        assert_eq!((raised.second(), raised.millisecond()), (30, 500));
+        assert_eq!(
+            seconds.add_milliseconds(1_000).precision(),
+            Precision::Seconds
+        );
+        assert_eq!(
+            seconds.add_nanoseconds(-2_000).precision(),
+            Precision::Microseconds
+        );
+        assert_eq!(
+            seconds.add_nanoseconds(1).precision(),
+            Precision::Nanoseconds
+        );
+        assert_eq!(
+            date_time.add_milliseconds(1).precision(),
+            Precision::Nanoseconds
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let chronology = load_chronology("UTC");
+        let date_time = |day, hour, minute, second| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(2016)
+                .month(12)
+                .day(day)
+                .hour(hour)
+                .minute(minute)
+                .second(second)
+                .build()
+        };
+        let leap_second = date_time(31, 23, 59, 60);
+        let mut date_times = vec![
+            date_time(31, 23, 59, 59),
+            leap_second.add_seconds(1),
+            date_time(1, 0, 0, 0),
+            leap_second.clone(),
+            date_time(31, 0, 0, 0),
+        ];
+        date_times.sort();
+        assert_eq!(
+            date_times,
+            vec![
+                date_time(1, 0, 0, 0),
+                date_time(31, 0, 0, 0),
+                date_time(31, 23, 59, 59),
+                leap_second.clone(),
+                leap_second.add_seconds(1),
+            ]
+        );
+        // Within a chronology the order is the same as the order of the instants.
+        for pair in date_times.windows(2) {
+            let (instant, next): (InstantS64, InstantS64) =
+                (pair[0].to_instant().unwrap(), pair[1].to_instant().unwrap());
+            assert!(instant < next);
+            assert_eq!(pair[0].cmp_instant(&pair[1]), Ordering::Less);
+        }
+
+        // Equal dates and times of different precision are ordered by precision, but are at the
+        // same instant.
+        let with_millisecond = leap_second.with_millisecond(0).unwrap();
+        assert_ne!(with_millisecond, leap_second);
+        assert!(leap_second < with_millisecond);
+        assert!(with_millisecond < leap_second.with_millisecond(1).unwrap());
+        assert_eq!(with_millisecond.cmp_instant(&leap_second), Ordering::Equal);
+
+        // The same date and time in another chronology isn't equal, but compares equal by instant.
+        let other_chronology = load_chronology("UTC");
+        let other = DateTime::builder()
+            .chronology(&other_chronology)
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .build();
+        assert_ne!(other, leap_second);
+        assert_ne!(other.cmp(&leap_second), Ordering::Equal);
+        assert_eq!(other.cmp(&leap_second), leap_second.cmp(&other).reverse());
+        assert_eq!(other.cmp_instant(&leap_second), Ordering::Equal);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::{BTreeMap, HashSet};
+
+        let chronology = load_chronology("UTC");
+        let date_time = DateTime::builder()
+            .chronology(&chronology)
+            .year(2024)
+            .month(2)
+            .day(29)
+            .hour(12)
+            .minute(0)
+            .second(0)
+            .build();
+        let later = date_time.add_seconds(1);
+
+        let mut set = HashSet::new();
+        assert!(set.insert(date_time.clone()));
+        assert!(set.insert(later.clone()));
+        assert!(set.insert(date_time.with_millisecond(0).unwrap()));
+        assert!(!set.insert(later.add_seconds(-1)));
+        assert_eq!(set.len(), 3);
+
+        let mut map = BTreeMap::new();
+        map.insert(later.clone(), "later");
+        map.insert(date_time.clone(), "earlier");
+        assert_eq!(map.get(&later.add_seconds(-1)), Some(&"earlier"));
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            ["earlier", "later"]
+        );
+    }
+
+    #[test]
+    fn carry_policy() {
+        let chronology = load_chronology("UTC");
+        let date = |year, month, day| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(12)
+                .minute(0)
+                .second(0)
+                .build()
+        };
+        let january_31 = date(2023, 1, 31);
+        let with_carry = january_31.add_months(1);
+        assert_eq!(with_carry.days_carry(), 3);
+        assert_eq!(
+            with_carry.resolve(CarryPolicy::Constrain),
+            Some(date(2023, 2, 28))
+        );
+        assert_eq!(
+            with_carry.resolve(CarryPolicy::Overflow),
+            Some(date(2023, 3, 3))
+        );
+        assert_eq!(with_carry.resolve(CarryPolicy::Reject), None);
+        assert_eq!(
+            with_carry.resolve(CarryPolicy::Absorb),
+            Some(date(2023, 2, 28))
+        );
+
+        assert_eq!(
+            january_31.checked_add_months_with(1, CarryPolicy::Overflow),
+            Some(date(2023, 3, 3))
+        );
+        assert_eq!(
+            january_31.checked_add_months_with(2, CarryPolicy::Reject),
+            Some(date(2023, 3, 31))
+        );
+        assert_eq!(
+            date(2024, 2, 29).checked_add_years_with(1, CarryPolicy::Overflow),
+            Some(date(2025, 3, 1))
+        );
+        assert_eq!(
+            date(2024, 2, 29).checked_add_years_with(1, CarryPolicy::Reject),
+            None
+        );
+        assert_eq!(
+            date(2024, 2, 29).checked_add_days_with(1, CarryPolicy::Reject),
+            Some(date(2024, 3, 1))
+        );
+        assert_eq!(
+            date(9999, 12, 31)
+                .checked_add_months(0)
+                .unwrap()
+                .resolve(CarryPolicy::Overflow),
+            Some(date(9999, 12, 31))
+        );
+    }
+
+    #[test]
+    fn carry_policy_with_leap_second() {
+        let chronology = load_chronology("UTC");
+        let leap_second = DateTime::builder()
+            .chronology(&chronology)
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .build();
+        let end_of_day = |year| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(12)
+                .day(31)
+                .hour(23)
+                .minute(59)
+                .second(59)
+                .build()
+        };
+
+        // The next year doesn't have a leap second, so the time is clamped to 23:59:59.
+        let with_carry = leap_second.add_years(1);
+        assert_eq!(with_carry.seconds_carry(), 1);
+        assert_eq!(with_carry.clone().drop_carry(), end_of_day(2017));
+        assert_eq!(
+            with_carry.resolve(CarryPolicy::Overflow),
+            Some(end_of_day(2017).add_seconds(1))
+        );
+        assert_eq!(with_carry.resolve(CarryPolicy::Reject), None);
+
+        // The time is clamped to the last second of the shorter day, not past it.
+        let with_carry = leap_second.add_months(-1);
+        assert_eq!(with_carry.seconds_carry(), 1);
+        assert_eq!(
+            with_carry.drop_carry(),
+            end_of_day(2016).add_days(-31).unwrap()
+        );
+        assert_eq!(
+            leap_second.checked_add_minutes_with(-1, CarryPolicy::Constrain),
+            Some(leap_second.add_seconds(-61))
+        );
+        assert_eq!(
+            leap_second.checked_add_hours_with(-1, CarryPolicy::Overflow),
+            Some(leap_second.add_seconds(-3_600))
+        );
+    }
+
+    #[test]
+    fn absorb_carry() {
+        let chronology = load_chronology("UTC");
+        let date_time = DateTime::builder()
+            .chronology(&chronology)
+            .year(2023)
+            .month(2)
+            .day(25)
+            .hour(23)
+            .minute(59)
+            .second(58)
+            .build();
+
+        let absorbed = DateTimeWithCarry::new(date_time.clone(), 0, 2).absorb_carry();
+        assert_eq!(absorbed.seconds_carry(), 1);
+        assert_eq!(absorbed.clone().drop_carry(), date_time.add_seconds(1));
+
+        let absorbed = DateTimeWithCarry::new(date_time.clone(), 5, 0).absorb_carry();
+        assert_eq!(absorbed.days_carry(), 2);
+        assert_eq!(absorbed.drop_carry(), date_time.add_days(3).unwrap());
+
+        // Onto the day of a leap second, which has room for one more second.
+        let date_time = date_time.with_year(2016).unwrap().with_month(12).unwrap();
+        let absorbed = DateTimeWithCarry::new(date_time.clone(), 6, 3).absorb_carry();
+        assert_eq!((absorbed.days_carry(), absorbed.seconds_carry()), (0, 1));
+        assert_eq!(absorbed.drop_carry().second(), 60);
+
+        // Seconds are only absorbed up to the end of the minute.
+        let date_time = date_time.with_hour(12).unwrap().with_minute(30).unwrap();
+        let absorbed = DateTimeWithCarry::new(date_time.clone(), 0, 3).absorb_carry();
+        assert_eq!(absorbed.seconds_carry(), 2);
+        assert_eq!(absorbed.drop_carry(), date_time.add_seconds(1));
+    }
+
+    #[test]
+    fn calendar_accessors() {
+        let date = |year, month, day| DateTime::builder().year(year).month(month).day(day).build();
+
+        let date_time = date(2024, 5, 17);
+        assert_eq!(date_time.weekday(), Weekday::Friday);
+        assert_eq!(date_time.month_of_year(), Month::May);
+        assert_eq!(date_time.quarter(), 2);
+        assert_eq!(date_time.day_of_year(), 138);
+        assert_eq!(date_time.days_in_month(), 31);
+        assert_eq!(date_time.days_in_year(), 366);
+        assert!(date_time.is_leap_year());
+
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(0, 1, 1).weekday(), Weekday::Saturday);
+        assert_eq!(date(9999, 12, 31).weekday(), Weekday::Friday);
+        assert_eq!(date(2023, 12, 31).day_of_year(), 365);
+        assert_eq!(date(2024, 12, 31).day_of_year(), 366);
+        assert_eq!(date(2023, 2, 1).days_in_month(), 28);
+        assert_eq!(date(2000, 2, 1).days_in_month(), 29);
+        assert_eq!(date(1900, 2, 1).days_in_month(), 28);
+        assert!(!date(1900, 2, 1).is_leap_year());
+        assert_eq!(date(1900, 2, 1).days_in_year(), 365);
+        assert_eq!(date(2024, 12, 31).quarter(), 4);
+    }
+
+    #[test]
+    fn iso_week() {
+        let date = |year, month, day| DateTime::builder().year(year).month(month).day(day).build();
+
+        assert_eq!(date(2024, 5, 17).iso_week(), (2024, 20));
+        assert_eq!(date(2024, 1, 1).iso_week(), (2024, 1));
+        // 2021-01-01 is a Friday, in the last week of 2020, which has 53 weeks.
+        assert_eq!(date(2021, 1, 1).iso_week(), (2020, 53));
+        assert_eq!(date(2021, 1, 3).iso_week(), (2020, 53));
+        assert_eq!(date(2021, 1, 4).iso_week(), (2021, 1));
+        // 2023-01-01 is a Sunday, in the last week of 2022, which has 52 weeks.
+        assert_eq!(date(2023, 1, 1).iso_week(), (2022, 52));
+        // 2024-12-30 is a Monday, in the first week of 2025.
+        assert_eq!(date(2024, 12, 29).iso_week(), (2024, 52));
+        assert_eq!(date(2024, 12, 30).iso_week(), (2025, 1));
+        assert_eq!(date(2026, 12, 31).iso_week(), (2026, 53));
+        assert_eq!(date(0, 1, 1).iso_week(), (-1, 52));
+        assert_eq!(date(9999, 12, 31).iso_week(), (9999, 52));
+    }
 }
@@ -1,7 +1,9 @@
+use crate::gregorian_normalized_date;
 use crate::gregorian_normalized_date::GregorianNormalizedDate;
+use crate::iso8601::carry_policy::CarryPolicy;
 use crate::iso8601::chronology::{load_chronology, Chronology};
 use crate::iso8601::precision::Precision;
-use crate::iso8601::DateTime;
+use crate::iso8601::{DateTime, DateTimeWithCarry};
 use crate::zoneinfo::SegmentLookupResult;
 use std::cmp::max;
 use std::fmt::Debug;
@@ -21,6 +23,7 @@ pub struct DateTimeBuilder {
     nanosecond: Option<u16>,
     offset_hour: Option<u8>,
     offset_minute: Option<u8>,
+    carry_policy: Option<CarryPolicy>,
 }
 
 #[derive(Eq, PartialEq)]
@@ -52,6 +55,27 @@ impl DateTimeBuilder {
         Self::default()
     }
 
+    // Returns a builder with every component and the precision of the given DateTime, so that
+    // one component can be replaced.
+    pub(super) fn from_date_time(date_time: &DateTime) -> Self {
+        DateTimeBuilder {
+            chronology: Some(date_time.chronology().clone()),
+            precision: Some(date_time.precision()),
+            year: Some(date_time.year()),
+            month: Some(date_time.month()),
+            day: Some(date_time.day()),
+            hour: Some(date_time.hour()),
+            minute: Some(date_time.minute()),
+            second: Some(date_time.second()),
+            millisecond: Some(date_time.millisecond()),
+            microsecond: Some(date_time.microsecond()),
+            nanosecond: Some(date_time.nanosecond()),
+            offset_hour: None,
+            offset_minute: None,
+            carry_policy: None,
+        }
+    }
+
     pub fn chronology(&mut self, chronology: &Chronology) -> &mut Self {
         self.chronology = Some(chronology.clone());
         self
@@ -124,6 +148,14 @@ impl DateTimeBuilder {
         self
     }
 
+    /// Sets what to do with a day past the end of the month, or a second of 60 in a minute
+    /// without a leap second. By default they are rejected. Other invalid values, like a second of
+    /// 61, are always rejected.
+    pub fn carry_policy(&mut self, carry_policy: CarryPolicy) -> &mut Self {
+        self.carry_policy = Some(carry_policy);
+        self
+    }
+
     pub fn build(&self) -> DateTime {
         match self.checked_build() {
             Ok(dt) => dt,
@@ -138,45 +170,47 @@ impl DateTimeBuilder {
             None => load_chronology("UTC"),
         };
         let year = self.year.expect("No year provided");
+        // Components finer than the precision default to the start of the unit of the precision,
+        // unless they are given, as when replacing a component of a DateTime.
         let month = if precision >= Precision::Months {
             self.month.expect("No month provided")
         } else {
-            0
+            self.month.unwrap_or(1)
         };
         let day = if precision >= Precision::Days {
             self.day.expect("No day provided")
         } else {
-            0
+            self.day.unwrap_or(1)
         };
         let hour = if precision >= Precision::Hours {
             self.hour.expect("No hour provided")
         } else {
-            0
+            self.hour.unwrap_or(0)
         };
         let minute = if precision >= Precision::Minutes {
             self.minute.expect("No minute provided")
         } else {
-            0
+            self.minute.unwrap_or(0)
         };
         let second = if precision >= Precision::Seconds {
             self.second.expect("No second provided")
         } else {
-            0
+            self.second.unwrap_or(0)
         };
         let millisecond = if precision >= Precision::Milliseconds {
             self.millisecond.expect("No millisecond provided")
         } else {
-            0
+            self.millisecond.unwrap_or(0)
         };
         let microsecond = if precision >= Precision::Microseconds {
             self.microsecond.expect("No microsecond provided")
         } else {
-            0
+            self.microsecond.unwrap_or(0)
         };
         let nanosecond = if precision >= Precision::Nanoseconds {
             self.nanosecond.expect("No nanosecond provided")
         } else {
-            0
+            self.nanosecond.unwrap_or(0)
         };
         let offset_hour = self.offset_hour.unwrap_or(0);
         let offset_minute = self.offset_minute.unwrap_or(0);
@@ -184,7 +218,21 @@ impl DateTimeBuilder {
         if year > 9999 {
             return Err(Error::DateTimeOutOfBounds);
         }
-        let gnd = GregorianNormalizedDate::from_date(year as i32, month, day)?;
+        let carry_policy = self.carry_policy.unwrap_or_default();
+        let (gnd, days_carry) = match GregorianNormalizedDate::from_date(year as i32, month, day) {
+            Err(gregorian_normalized_date::Error::InvalidDate)
+                if carry_policy != CarryPolicy::Reject
+                    && (1..=12).contains(&month)
+                    && (29..=31).contains(&day) =>
+            {
+                // The day is past the end of the month, so take the last day and carry the rest.
+                let last_day =
+                    GregorianNormalizedDate::from_date(year as i32, month, 28)?.days_in_month();
+                let gnd = GregorianNormalizedDate::from_date(year as i32, month, last_day)?;
+                (gnd, (day - last_day) as u32)
+            }
+            result => (result?, 0),
+        };
 
         if hour >= 24 || minute >= 60 {
             return Err(Error::InvalidDateTime);
@@ -208,20 +256,26 @@ impl DateTimeBuilder {
             }
         };
 
-        if second >= minute_length
-            || millisecond >= 1000
-            || microsecond >= 1000
-            || nanosecond >= 1000
-        {
+        if millisecond >= 1000 || microsecond >= 1000 || nanosecond >= 1000 {
             return Err(Error::InvalidDateTime);
         }
+        // Only a second of 60 can be carried, since that is the second that exists in some
+        // minutes and not in others.
+        let (second, seconds_carry) = if second < minute_length {
+            (second, 0)
+        } else if carry_policy == CarryPolicy::Reject || second > 60 {
+            return Err(Error::InvalidDateTime);
+        } else {
+            (minute_length - 1, second - (minute_length - 1))
+        };
 
         let second = hour as u32 * 3600 + minute as u32 * 60 + second as u32;
         let nanosecond =
             millisecond as u32 * 1_000_000 + microsecond as u32 * 1_000 + nanosecond as u32;
-        Ok(DateTime::new(
-            chronology, precision, gnd, second, nanosecond,
-        ))
+        let date_time = DateTime::new(chronology, precision, gnd, second, nanosecond);
+        DateTimeWithCarry::new(date_time, days_carry, seconds_carry as u64)
+            .resolve(carry_policy)
+            .ok_or(Error::DateTimeOutOfBounds)
     }
 }
 
@@ -433,4 +487,126 @@ mod tests {
             .checked_build();
         assert_eq!(result, Err(Error::InvalidDateTime));
     }
+
+    #[test]
+    fn carry_policy() {
+        let chronology = load_chronology("UTC");
+        let mut builder = DateTimeBuilder::new();
+        builder
+            .chronology(&chronology)
+            .year(2023)
+            .month(2)
+            .day(31)
+            .hour(12)
+            .minute(0)
+            .second(0);
+        let date = |month, day| {
+            DateTimeBuilder::new()
+                .chronology(&chronology)
+                .year(2023)
+                .month(month)
+                .day(day)
+                .hour(12)
+                .minute(0)
+                .second(0)
+                .build()
+        };
+        assert_eq!(builder.checked_build(), Err(Error::InvalidDateTime));
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Reject).checked_build(),
+            Err(Error::InvalidDateTime)
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Constrain).checked_build(),
+            Ok(date(2, 28))
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Absorb).checked_build(),
+            Ok(date(2, 28))
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Overflow).checked_build(),
+            Ok(date(3, 3))
+        );
+        assert_eq!(builder.day(32).checked_build(), Err(Error::InvalidDateTime));
+
+        // A second of 60 without a leap second.
+        builder.day(28).hour(23).minute(59).second(60);
+        let end_of_day = DateTimeBuilder::new()
+            .chronology(&chronology)
+            .year(2023)
+            .month(2)
+            .day(28)
+            .hour(23)
+            .minute(59)
+            .second(59)
+            .build();
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Constrain).checked_build(),
+            Ok(end_of_day.clone())
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Overflow).checked_build(),
+            Ok(end_of_day.add_seconds(1))
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Reject).checked_build(),
+            Err(Error::InvalidDateTime)
+        );
+
+        // Absorbing stops at the end of the minute, while overflowing goes into the next one.
+        builder.hour(12).minute(30).second(60);
+        let end_of_minute = DateTimeBuilder::new()
+            .chronology(&chronology)
+            .year(2023)
+            .month(2)
+            .day(28)
+            .hour(12)
+            .minute(30)
+            .second(59)
+            .build();
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Absorb).checked_build(),
+            Ok(end_of_minute.clone())
+        );
+        assert_eq!(
+            builder.carry_policy(CarryPolicy::Overflow).checked_build(),
+            Ok(end_of_minute
+                .with_minute(31)
+                .unwrap()
+                .with_second(0)
+                .unwrap())
+        );
+
+        // Overflowing past the last day.
+        let result = DateTimeBuilder::new()
+            .year(9999)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .carry_policy(CarryPolicy::Overflow)
+            .checked_build();
+        assert_eq!(result, Err(Error::DateTimeOutOfBounds));
+
+        // Seconds past 60 don't exist in any minute, so they aren't carried.
+        for second in [61, 200] {
+            for policy in [
+                CarryPolicy::Constrain,
+                CarryPolicy::Overflow,
+                CarryPolicy::Reject,
+                CarryPolicy::Absorb,
+            ] {
+                for hour in [12, 23] {
+                    let result = builder
+                        .hour(hour)
+                        .second(second)
+                        .carry_policy(policy)
+                        .checked_build();
+                    assert_eq!(result, Err(Error::InvalidDateTime), "{second} {policy:?}");
+                }
+            }
+        }
+    }
 }
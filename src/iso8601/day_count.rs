@@ -0,0 +1,332 @@
+use num_integer::Integer;
+use num_rational::Ratio;
+
+use crate::instant::Tick;
+use crate::iso8601::chronology::Chronology;
+use crate::iso8601::precision::Precision;
+use crate::iso8601::{DateTime, SECONDS_PER_DAY};
+use crate::{Instant, Scale};
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+// .NET ticks are 100 nanoseconds long and count from 0001-01-01 00:00:00.
+const DOTNET_TICKS_PER_SECOND: i64 = 10_000_000;
+const DOTNET_NANOSECONDS_PER_TICK: u32 = 100;
+const DOTNET_TICKS_PER_DAY: i64 = DOTNET_TICKS_PER_SECOND * SECONDS_PER_DAY as i64;
+// Number of days from 0001-01-01 to 1970-01-01.
+const DOTNET_EPOCH_OFFSET_DAYS: i64 = 719_162;
+
+// The 1900 date system of Excel (inherited from Lotus 1-2-3) treats 1900 as a leap year, so
+// serial number 60 is the nonexistent date 1900-02-29. Dates before it are off by one day
+// compared to dates after it.
+const EXCEL_1900_PHANTOM_LEAP_DAY: i64 = 60;
+
+/// A count of (possibly fractional) days since some epoch, as used by astronomers and
+/// spreadsheets.
+///
+/// The fractional part of a day count is the elapsed part of the UTC day. Days that end with a
+/// leap second are 86,401 seconds long, so a second of such a day is a slightly smaller fraction
+/// than a second of a normal day. This is the usual "quasi Julian date" convention for UTC.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum DayCount {
+    /// Julian date. Day 0 starts at noon on -4713-11-24 in the proleptic Gregorian calendar.
+    JulianDate,
+    /// Modified Julian date, i.e. the Julian date minus 2,400,000.5. Day 0 is 1858-11-17.
+    ModifiedJulianDate,
+    /// Rata Die. Day 1 is 0001-01-01 in the proleptic Gregorian calendar.
+    RataDie,
+    /// The Excel/Lotus 1-2-3 1900 date system. Day 1 is 1900-01-01, and day 60 is the
+    /// nonexistent date 1900-02-29.
+    Excel1900,
+    /// The Excel 1904 date system used by early versions of Excel for Macintosh. Day 0 is
+    /// 1904-01-01.
+    Excel1904,
+}
+
+impl DayCount {
+    // Returns the day count at the start of the given day (counted from the Unix epoch).
+    fn at_fixed_day(self, day: i64) -> Ratio<i128> {
+        let day = day as i128;
+        match self {
+            DayCount::JulianDate => Ratio::new(2 * (day + 2_440_587) + 1, 2),
+            DayCount::ModifiedJulianDate => Ratio::from_integer(day + 40_587),
+            DayCount::RataDie => Ratio::from_integer(day + 719_163),
+            DayCount::Excel1900 => {
+                let serial = day + 25_569;
+                if serial > EXCEL_1900_PHANTOM_LEAP_DAY as i128 {
+                    Ratio::from_integer(serial)
+                } else {
+                    Ratio::from_integer(serial - 1)
+                }
+            }
+            DayCount::Excel1904 => Ratio::from_integer(day + 24_107),
+        }
+    }
+
+    // Splits a day count into the day (counted from the Unix epoch) and the elapsed fraction of
+    // that day. Returns None if the day count refers to a day that doesn't exist.
+    fn split(self, value: Ratio<i128>) -> Option<(i128, Ratio<i128>)> {
+        // The Julian date starts at noon, so shift it by half a day to get whole days.
+        let value = match self {
+            DayCount::JulianDate => value + Ratio::new(1, 2),
+            _ => value,
+        };
+        let whole = value.floor();
+        let fraction = value - whole;
+        let whole = whole.to_integer();
+        let day = match self {
+            DayCount::JulianDate => whole - 2_440_588,
+            DayCount::ModifiedJulianDate => whole - 40_587,
+            DayCount::RataDie => whole - 719_163,
+            DayCount::Excel1900 => match whole.cmp(&(EXCEL_1900_PHANTOM_LEAP_DAY as i128)) {
+                std::cmp::Ordering::Less => whole - 25_568,
+                std::cmp::Ordering::Equal => return None,
+                std::cmp::Ordering::Greater => whole - 25_569,
+            },
+            DayCount::Excel1904 => whole - 24_107,
+        };
+        Some((day, fraction))
+    }
+}
+
+impl DateTime {
+    /// Returns the date and time as a day count, with the time of day as an exact fraction.
+    pub fn to_day_count(&self, day_count: DayCount) -> Ratio<i128> {
+        let (day, second, nanosecond) = self.to_fixed_day();
+        let day_length = self.chronology().leap_seconds().day_length(day);
+        let elapsed = second as i128 * NANOSECONDS_PER_SECOND as i128 + nanosecond as i128;
+        let fraction = Ratio::new(elapsed, day_length as i128 * NANOSECONDS_PER_SECOND as i128);
+        day_count.at_fixed_day(day as i64) + fraction
+    }
+
+    /// Creates a DateTime from a day count. The time of day is truncated to whole nanoseconds.
+    /// Returns None if the result is outside the range of DateTime, or if the day count refers
+    /// to a day that doesn't exist (i.e. the phantom 1900-02-29 of the Excel 1900 date system).
+    pub fn from_day_count(
+        value: Ratio<i128>,
+        day_count: DayCount,
+        chronology: &Chronology,
+    ) -> Option<DateTime> {
+        let (day, fraction) = day_count.split(value)?;
+        let day: i32 = day.try_into().ok()?;
+        let day_length = chronology.leap_seconds().day_length(day);
+        let elapsed = fraction * (day_length as i128 * NANOSECONDS_PER_SECOND as i128);
+        let (second, nanosecond) = elapsed
+            .floor()
+            .to_integer()
+            .div_rem(&(NANOSECONDS_PER_SECOND as i128));
+        DateTime::from_fixed_day(
+            chronology,
+            Precision::Nanoseconds,
+            day,
+            second as u32,
+            nanosecond as u32,
+        )
+    }
+
+    /// Returns the date and time as a number of .NET ticks (100 nanosecond intervals since
+    /// 0001-01-01 00:00:00). .NET has no concept of leap seconds, so a time within a leap second
+    /// is mapped to the last tick of the day. Returns None for dates before year 1.
+    pub fn to_dotnet_ticks(&self) -> Option<i64> {
+        let (day, second, nanosecond) = self.to_fixed_day();
+        let days = day as i64 + DOTNET_EPOCH_OFFSET_DAYS;
+        if days < 0 {
+            return None;
+        }
+        let tick_of_day = if second < SECONDS_PER_DAY {
+            second as i64 * DOTNET_TICKS_PER_SECOND
+                + (nanosecond / DOTNET_NANOSECONDS_PER_TICK) as i64
+        } else {
+            DOTNET_TICKS_PER_DAY - 1
+        };
+        Some(days * DOTNET_TICKS_PER_DAY + tick_of_day)
+    }
+
+    /// Creates a DateTime from a number of .NET ticks. Returns None if the ticks are negative
+    /// or outside the range of DateTime.
+    pub fn from_dotnet_ticks(ticks: i64, chronology: &Chronology) -> Option<DateTime> {
+        if ticks < 0 {
+            return None;
+        }
+        let (days, tick_of_day) = ticks.div_rem(&DOTNET_TICKS_PER_DAY);
+        let (second, tick) = tick_of_day.div_rem(&DOTNET_TICKS_PER_SECOND);
+        let day: i32 = (days - DOTNET_EPOCH_OFFSET_DAYS).try_into().ok()?;
+        DateTime::from_fixed_day(
+            chronology,
+            Precision::Nanoseconds,
+            day,
+            second as u32,
+            tick as u32 * DOTNET_NANOSECONDS_PER_TICK,
+        )
+    }
+}
+
+impl<T: Tick, S: Scale> Instant<T, S> {
+    /// Returns the instant as a day count in UTC, with leap seconds taken from the given
+    /// chronology. Returns None if the instant is outside the range of DateTime.
+    pub fn to_day_count(
+        &self,
+        day_count: DayCount,
+        chronology: &Chronology,
+    ) -> Option<Ratio<i128>> {
+        Some(
+            DateTime::from_instant(*self, chronology)
+                .ok()?
+                .to_day_count(day_count),
+        )
+    }
+
+    /// Creates an instant from a day count in UTC, with leap seconds taken from the given
+    /// chronology. Any part of the day count that can't be represented in the scale of the
+    /// instant is truncated.
+    pub fn from_day_count(
+        value: Ratio<i128>,
+        day_count: DayCount,
+        chronology: &Chronology,
+    ) -> Option<Self> {
+        DateTime::from_day_count(value, day_count, chronology)?
+            .to_instant()
+            .ok()
+    }
+
+    /// Returns the instant as a number of .NET ticks. See [DateTime::to_dotnet_ticks].
+    pub fn to_dotnet_ticks(&self, chronology: &Chronology) -> Option<i64> {
+        DateTime::from_instant(*self, chronology)
+            .ok()?
+            .to_dotnet_ticks()
+    }
+
+    /// Creates an instant from a number of .NET ticks. See [DateTime::from_dotnet_ticks].
+    pub fn from_dotnet_ticks(ticks: i64, chronology: &Chronology) -> Option<Self> {
+        DateTime::from_dotnet_ticks(ticks, chronology)?
+            .to_instant()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::load_chronology;
+    use crate::InstantNs128;
+
+    fn date_time(chronology: &Chronology, year: u16, month: u8, day: u8) -> DateTime {
+        DateTime::builder()
+            .chronology(chronology)
+            .year(year)
+            .month(month)
+            .day(day)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .build()
+    }
+
+    #[test]
+    fn day_count_epochs() {
+        let chronology = load_chronology("UTC");
+
+        // J2000.0 is 2000-01-01 12:00:00.
+        let dt = date_time(&chronology, 2000, 1, 1).add_hours(12).unwrap();
+        assert_eq!(
+            dt.to_day_count(DayCount::JulianDate),
+            Ratio::from_integer(2_451_545)
+        );
+
+        let dt = date_time(&chronology, 1858, 11, 17);
+        assert_eq!(
+            dt.to_day_count(DayCount::ModifiedJulianDate),
+            Ratio::from_integer(0)
+        );
+
+        let dt = date_time(&chronology, 1, 1, 1);
+        assert_eq!(dt.to_day_count(DayCount::RataDie), Ratio::from_integer(1));
+
+        let dt = date_time(&chronology, 1904, 1, 1);
+        assert_eq!(dt.to_day_count(DayCount::Excel1904), Ratio::from_integer(0));
+    }
+
+    #[test]
+    fn excel_1900_phantom_leap_day() {
+        let chronology = load_chronology("UTC");
+        let check = |year, month, day, serial| {
+            let dt = date_time(&chronology, year, month, day);
+            assert_eq!(
+                dt.to_day_count(DayCount::Excel1900),
+                Ratio::from_integer(serial)
+            );
+            let from_serial = DateTime::from_day_count(
+                Ratio::from_integer(serial),
+                DayCount::Excel1900,
+                &chronology,
+            )
+            .unwrap();
+            assert_eq!(from_serial.to_fixed_day(), dt.to_fixed_day());
+        };
+        check(1900, 1, 1, 1);
+        check(1900, 2, 28, 59);
+        check(1900, 3, 1, 61);
+        check(2024, 5, 17, 45429);
+        assert!(DateTime::from_day_count(
+            Ratio::from_integer(60),
+            DayCount::Excel1900,
+            &chronology
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn leap_second_day_fraction() {
+        let chronology = load_chronology("UTC");
+        // 1998-12-31 ends with a leap second, so it's 86,401 seconds long and the leap second
+        // itself starts 86,400/86,401 of the way into the day.
+        let dt = date_time(&chronology, 1998, 12, 31)
+            .add_hours(23)
+            .unwrap()
+            .add_minutes(59)
+            .unwrap()
+            .add_seconds(60);
+        assert_eq!(dt.second(), 60);
+        let mjd = dt.to_day_count(DayCount::ModifiedJulianDate);
+        assert_eq!(mjd, Ratio::from_integer(51178) + Ratio::new(86_400, 86_401));
+        let round_trip =
+            DateTime::from_day_count(mjd, DayCount::ModifiedJulianDate, &chronology).unwrap();
+        assert_eq!(round_trip.to_fixed_day(), dt.to_fixed_day());
+    }
+
+    #[test]
+    fn instant_round_trip() {
+        let chronology = load_chronology("UTC");
+        let instant = InstantNs128::from_ticks_since_epoch(1_700_000_000_123_456_789);
+        for day_count in [
+            DayCount::JulianDate,
+            DayCount::ModifiedJulianDate,
+            DayCount::RataDie,
+            DayCount::Excel1900,
+            DayCount::Excel1904,
+        ] {
+            let value = instant.to_day_count(day_count, &chronology).unwrap();
+            let round_trip = InstantNs128::from_day_count(value, day_count, &chronology).unwrap();
+            assert_eq!(round_trip, instant);
+        }
+
+        let ticks = instant.to_dotnet_ticks(&chronology).unwrap();
+        let round_trip = InstantNs128::from_dotnet_ticks(ticks, &chronology).unwrap();
+        assert_eq!(
+            round_trip,
+            InstantNs128::from_ticks_since_epoch(1_700_000_000_123_456_700)
+        );
+    }
+
+    #[test]
+    fn dotnet_ticks() {
+        let chronology = load_chronology("UTC");
+        // DateTime.UnixEpoch.Ticks
+        let dt = date_time(&chronology, 1970, 1, 1);
+        assert_eq!(dt.to_dotnet_ticks(), Some(621_355_968_000_000_000));
+        let dt = date_time(&chronology, 1, 1, 1);
+        assert_eq!(dt.to_dotnet_ticks(), Some(0));
+        let dt = date_time(&chronology, 0, 12, 31);
+        assert_eq!(dt.to_dotnet_ticks(), None);
+    }
+}
@@ -1,13 +1,37 @@
+pub use anchored_steps::AnchoredSteps;
+pub use carry_policy::CarryPolicy;
 pub use chronology::{load_chronology, Chronology};
+pub use date::Date;
 pub use date_time::DateTime;
+pub use date_time::DateTimeConversionError;
 pub use date_time::DateTimeWithCarry;
 pub use date_time_builder::DateTimeBuilder;
+pub use date_time_builder::Error;
+pub use day_count::DayCount;
+pub use month::Month;
+pub use parse_error::ParseError;
+pub use period::Period;
+pub use precision::Precision;
+pub use range_relation::RangeRelation;
+pub use time::Time;
+pub use weekday::Weekday;
 
+mod anchored_steps;
+mod carry_policy;
 mod chronology;
+mod date;
 mod date_time;
 mod date_time_builder;
+mod day_count;
+mod month;
+mod parse_error;
+mod period;
 mod precision;
+mod range_relation;
+mod time;
+mod truncation;
 mod util;
+mod weekday;
 
 const SECONDS_PER_DAY: u32 = 86_400;
 const SECONDS_PER_HOUR: u16 = 3_600;
@@ -15,3 +39,4 @@ const SECONDS_PER_MINUTE: u8 = 60;
 const HOURS_PER_DAY: u8 = 24;
 const MINUTES_PER_DAY: u16 = 1_440;
 const MINUTES_PER_HOUR: u8 = 60;
+const DAYS_PER_WEEK: u8 = 7;
@@ -0,0 +1,139 @@
+use std::ops::{Add, Sub};
+
+use crate::iso8601::util::days_in_month;
+
+const MONTHS_PER_YEAR: u8 = 12;
+
+/// A month of the Gregorian calendar. Adding or subtracting months wraps around the year.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Month {
+    January = 1,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// Every month from January to December.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// Returns the month with the given number, from 1 for January to 12 for December.
+    pub fn from_number(number: u8) -> Option<Month> {
+        (1..=MONTHS_PER_YEAR)
+            .contains(&number)
+            .then(|| Self::ALL[(number - 1) as usize])
+    }
+
+    /// The number of the month, from 1 for January to 12 for December.
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    pub fn succ(self) -> Month {
+        self + 1
+    }
+
+    pub fn pred(self) -> Month {
+        self - 1
+    }
+
+    /// The quarter of the year the month is in, from 1 to 4.
+    pub fn quarter(self) -> u8 {
+        (self.number() - 1) / 3 + 1
+    }
+
+    /// The number of days in the month in the given year.
+    pub fn length(self, year: i32) -> u8 {
+        days_in_month(year as i128, self.number())
+    }
+
+    /// Iterates over the months of the year starting with this one, e.g. for a fiscal year.
+    pub fn iter_from(self) -> impl Iterator<Item = Month> {
+        (0..MONTHS_PER_YEAR as i64).map(move |months| self + months)
+    }
+}
+
+impl Add<i64> for Month {
+    type Output = Month;
+
+    fn add(self, months: i64) -> Self::Output {
+        let months = months % MONTHS_PER_YEAR as i64;
+        let index = (self.number() as i64 - 1 + months).rem_euclid(MONTHS_PER_YEAR as i64);
+        Self::ALL[index as usize]
+    }
+}
+
+impl Sub<i64> for Month {
+    type Output = Month;
+
+    fn sub(self, months: i64) -> Self::Output {
+        // The remainder of i64::MIN can't be negated, so reduce it first.
+        self + -(months % MONTHS_PER_YEAR as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        for (index, month) in Month::ALL.into_iter().enumerate() {
+            assert_eq!(month.number() as usize, index + 1);
+            assert_eq!(Month::from_number(month.number()), Some(month));
+        }
+        assert_eq!(Month::from_number(0), None);
+        assert_eq!(Month::from_number(13), None);
+        let quarters: Vec<_> = Month::ALL.into_iter().map(Month::quarter).collect();
+        assert_eq!(quarters, vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Month::December.succ(), Month::January);
+        assert_eq!(Month::January.pred(), Month::December);
+        assert_eq!(Month::November + 14, Month::January);
+        assert_eq!(Month::February - 3, Month::November);
+        assert_eq!(Month::January - i64::MIN, Month::September);
+        assert_eq!(
+            Month::October.iter_from().take(4).collect::<Vec<_>>(),
+            vec![
+                Month::October,
+                Month::November,
+                Month::December,
+                Month::January
+            ]
+        );
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(Month::January.length(2023), 31);
+        assert_eq!(Month::February.length(2023), 28);
+        assert_eq!(Month::February.length(2024), 29);
+        assert_eq!(Month::February.length(1900), 28);
+        assert_eq!(Month::February.length(2000), 29);
+        assert_eq!(Month::April.length(2024), 30);
+    }
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+/// An error parsing a [Date](crate::iso8601::Date) or [Time](crate::iso8601::Time).
+#[derive(Error, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ParseError {
+    /// The string isn't in the expected format, which is given in the notation of ISO 8601.
+    #[error("expected the format {0}")]
+    InvalidFormat(&'static str),
+    /// The string is in the expected format but isn't a valid date or time, e.g. 2023-02-29.
+    #[error("invalid date or time")]
+    InvalidValue,
+}
+
+// Parses a non-empty string of ASCII digits, without a sign. Returns None if there are any other
+// characters or the value doesn't fit.
+pub(super) fn parse_digits(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(0_u32, |value, &digit| {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        value.checked_mul(10)?.checked_add((digit - b'0') as u32)
+    })
+}
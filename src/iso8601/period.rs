@@ -0,0 +1,519 @@
+use std::cmp::{max, Ordering};
+
+use crate::iso8601::carry_policy::CarryPolicy;
+use crate::iso8601::precision::Precision;
+use crate::iso8601::DateTime;
+use crate::DurationNs128;
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+
+// The time units of a Period with their length in nanoseconds, from the largest to the smallest.
+const TIME_UNITS: [(Precision, i128); 6] = [
+    (Precision::Hours, 3_600 * NANOSECONDS_PER_SECOND),
+    (Precision::Minutes, 60 * NANOSECONDS_PER_SECOND),
+    (Precision::Seconds, NANOSECONDS_PER_SECOND),
+    (Precision::Milliseconds, 1_000_000),
+    (Precision::Microseconds, 1_000),
+    (Precision::Nanoseconds, 1),
+];
+
+/// The calendar difference between two [DateTime]s, as returned by [DateTime::until]. All
+/// components have the same sign, which is negative if the second DateTime is earlier.
+///
+/// Years, months, weeks and days are calendar units whose length varies. The other components
+/// are exact, so an hour is always 3,600 seconds even if it contains a leap second.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Period {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+    pub microseconds: i64,
+    pub nanoseconds: i64,
+}
+
+impl Period {
+    /// Multiplies every component by the factor. Returns None on overflow.
+    pub fn checked_mul(&self, factor: i64) -> Option<Period> {
+        Some(Period {
+            years: self.years.checked_mul(factor)?,
+            months: self.months.checked_mul(factor)?,
+            weeks: self.weeks.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            hours: self.hours.checked_mul(factor)?,
+            minutes: self.minutes.checked_mul(factor)?,
+            seconds: self.seconds.checked_mul(factor)?,
+            milliseconds: self.milliseconds.checked_mul(factor)?,
+            microseconds: self.microseconds.checked_mul(factor)?,
+            nanoseconds: self.nanoseconds.checked_mul(factor)?,
+        })
+    }
+
+    fn get(&self, unit: Precision) -> i64 {
+        match unit {
+            Precision::Hours => self.hours,
+            Precision::Minutes => self.minutes,
+            Precision::Seconds => self.seconds,
+            Precision::Milliseconds => self.milliseconds,
+            Precision::Microseconds => self.microseconds,
+            Precision::Nanoseconds => self.nanoseconds,
+            _ => unreachable!("not a time unit"),
+        }
+    }
+
+    fn set(&mut self, unit: Precision, value: i64) {
+        *self.time_component(unit) = value;
+    }
+
+    fn time_component(&mut self, unit: Precision) -> &mut i64 {
+        match unit {
+            Precision::Hours => &mut self.hours,
+            Precision::Minutes => &mut self.minutes,
+            Precision::Seconds => &mut self.seconds,
+            Precision::Milliseconds => &mut self.milliseconds,
+            Precision::Microseconds => &mut self.microseconds,
+            Precision::Nanoseconds => &mut self.nanoseconds,
+            _ => unreachable!("not a time unit"),
+        }
+    }
+}
+
+impl DateTime {
+    /// Returns the exact time elapsed since the earlier DateTime, including any leap seconds in
+    /// between. The result is negative if the other DateTime is later.
+    pub fn duration_since(&self, earlier: &DateTime) -> DurationNs128 {
+        self.instant() - earlier.instant()
+    }
+
+    /// Returns the calendar difference from this DateTime to the other, using only the units
+    /// from `largest_unit` to `smallest_unit`. Millennia, centuries and decades are counted as
+    /// years. Weeks are only used if one of the units is weeks, so that the days from years to
+    /// nanoseconds aren't split into weeks. The difference is truncated towards zero to a whole
+    /// number of the smallest unit.
+    ///
+    /// Adding the result to this DateTime with [DateTime::checked_add_period] gives the other
+    /// DateTime when the smallest unit is nanoseconds, the same as `until` in JavaScript's
+    /// Temporal. Returns None if the largest unit is smaller than the smallest unit, or if a
+    /// component doesn't fit in an i64, which is possible for differences of more than 292 years
+    /// in nanoseconds.
+    pub fn until(
+        &self,
+        other: &DateTime,
+        largest_unit: Precision,
+        smallest_unit: Precision,
+    ) -> Option<Period> {
+        let largest_unit = max(largest_unit, Precision::Years);
+        let smallest_unit = max(smallest_unit, Precision::Years);
+        if largest_unit > smallest_unit {
+            return None;
+        }
+        let uses = |unit| (largest_unit..=smallest_unit).contains(&unit);
+
+        // Whether the date and time has passed the other one, going from self towards other.
+        let end = other.to_fixed_day();
+        let direction = if end < self.to_fixed_day() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        let sign = if direction == Ordering::Less { -1 } else { 1 };
+        let passes = |date_time: &DateTime| date_time.to_fixed_day().cmp(&end) == direction;
+
+        let mut period = Period::default();
+        let mut intermediate = self.clone();
+        if largest_unit <= Precision::Months {
+            // Start at the month of the other DateTime, and go back a month if the day or time
+            // of the month is past it.
+            let mut months = (other.year() as i64 - self.year() as i64) * 12 + other.month() as i64
+                - self.month() as i64;
+            while passes(&self.add_months_constrained(months)?) {
+                months -= sign;
+            }
+            if uses(Precision::Years) {
+                period.years = months / 12;
+            }
+            if uses(Precision::Months) {
+                period.months = months - period.years * 12;
+            }
+            intermediate = self.add_months_constrained(period.years * 12 + period.months)?;
+        }
+        if largest_unit <= Precision::Days && smallest_unit >= Precision::Weeks {
+            let (day, _, _) = intermediate.to_fixed_day();
+            let mut days = (end.0 - day) as i64;
+            while passes(&intermediate.add_days_constrained(days)?) {
+                days -= sign;
+            }
+            // Days are only split into weeks when weeks are asked for, as the largest unit or as
+            // the smallest, and not whenever a larger unit is.
+            if largest_unit == Precision::Weeks || smallest_unit == Precision::Weeks {
+                period.weeks = days / 7;
+            }
+            if uses(Precision::Days) {
+                period.days = days - period.weeks * 7;
+            }
+            intermediate = intermediate.add_days_constrained(period.weeks * 7 + period.days)?;
+        }
+        if smallest_unit >= Precision::Hours {
+            let mut nanoseconds =
+                other.instant().ticks_since_epoch() - intermediate.instant().ticks_since_epoch();
+            for (unit, length) in TIME_UNITS {
+                if uses(unit) {
+                    let value = nanoseconds / length;
+                    nanoseconds -= value * length;
+                    period.set(unit, value.try_into().ok()?);
+                }
+            }
+        }
+        Some(period)
+    }
+
+    pub fn add_period(&self, period: &Period) -> Self {
+        self.checked_add_period(period)
+            .expect("adding a period to a DateTime resulted in an out-of-bounds value")
+    }
+
+    /// Adds the components of the period in the order that [DateTime::until] takes them off:
+    /// first the years and months together with [DateTime::checked_add_months], dropping the
+    /// carry so that the day is clamped to the end of the month, then the weeks and days with
+    /// [DateTime::checked_add_days], dropping the carry in the same way, and finally the exact
+    /// time components with [DateTime::checked_add_seconds] and
+    /// [DateTime::checked_add_nanoseconds].
+    pub fn checked_add_period(&self, period: &Period) -> Option<Self> {
+        self.checked_add_period_with(period, CarryPolicy::Constrain)
+    }
+
+    /// Like [DateTime::checked_add_period], with the carries of adding the months and the days
+    /// resolved by the given policy instead of dropped.
+    pub fn checked_add_period_with(&self, period: &Period, policy: CarryPolicy) -> Option<Self> {
+        let months = period.years.checked_mul(12)?.checked_add(period.months)?;
+        let days = period.weeks.checked_mul(7)?.checked_add(period.days)?;
+        let nanoseconds = TIME_UNITS
+            .iter()
+            .map(|&(unit, length)| period.get(unit) as i128 * length)
+            .sum::<i128>();
+        let seconds = nanoseconds.div_euclid(NANOSECONDS_PER_SECOND);
+        let nanosecond = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND);
+        self.checked_add_months_with(months.try_into().ok()?, policy)?
+            .checked_add_days_with(days.try_into().ok()?, policy)?
+            .checked_add_seconds_and_nanoseconds(seconds.try_into().ok()?, nanosecond as u32)
+    }
+
+    fn add_months_constrained(&self, months: i64) -> Option<Self> {
+        self.checked_add_months_with(months.try_into().ok()?, CarryPolicy::Constrain)
+    }
+
+    fn add_days_constrained(&self, days: i64) -> Option<Self> {
+        self.checked_add_days_with(days.try_into().ok()?, CarryPolicy::Constrain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::load_chronology;
+    use crate::iso8601::Chronology;
+
+    fn date_time(
+        chronology: &Chronology,
+        (year, month, day): (u16, u8, u8),
+        (hour, minute, second): (u8, u8, u8),
+    ) -> DateTime {
+        DateTime::builder()
+            .chronology(chronology)
+            .year(year)
+            .month(month)
+            .day(day)
+            .hour(hour)
+            .minute(minute)
+            .second(second)
+            .build()
+    }
+
+    #[test]
+    fn until() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2024, 1, 31), (12, 0, 0));
+        let end = date_time(&chronology, (2025, 3, 1), (11, 30, 15));
+
+        let period = start
+            .until(&end, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                years: 1,
+                months: 1,
+                weeks: 0,
+                days: 0,
+                hours: 23,
+                minutes: 30,
+                seconds: 15,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), end);
+
+        let period = start
+            .until(&end, Precision::Months, Precision::Days)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                months: 13,
+                ..Default::default()
+            }
+        );
+
+        let period = start
+            .until(&end, Precision::Days, Precision::Hours)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                days: 394,
+                hours: 23,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            start.add_period(&period),
+            end.with_minute(0).unwrap().with_second(0).unwrap()
+        );
+
+        let period = start
+            .until(&end, Precision::Minutes, Precision::Seconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                minutes: 568_770,
+                seconds: 15,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), end);
+
+        assert_eq!(
+            start.until(&end, Precision::Seconds, Precision::Hours),
+            None
+        );
+        assert_eq!(start.until(&end, Precision::Days, Precision::Months), None);
+        // Units larger than years are counted as years, so they aren't in the wrong order.
+        assert!(start
+            .until(&end, Precision::Years, Precision::Millennia)
+            .is_some());
+    }
+
+    #[test]
+    fn until_in_weeks() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2024, 1, 1), (0, 0, 0));
+        let end = date_time(&chronology, (2024, 1, 11), (0, 0, 0));
+        let days = Period {
+            days: 10,
+            ..Default::default()
+        };
+        let weeks = Period {
+            weeks: 1,
+            days: 3,
+            ..Default::default()
+        };
+        for (largest_unit, smallest_unit, period) in [
+            (Precision::Years, Precision::Nanoseconds, days),
+            (Precision::Months, Precision::Days, days),
+            (Precision::Days, Precision::Days, days),
+            (Precision::Weeks, Precision::Nanoseconds, weeks),
+            (
+                Precision::Years,
+                Precision::Weeks,
+                Period {
+                    weeks: 1,
+                    ..Default::default()
+                },
+            ),
+        ] {
+            assert_eq!(
+                start.until(&end, largest_unit, smallest_unit),
+                Some(period),
+                "{largest_unit:?} to {smallest_unit:?}"
+            );
+        }
+        assert_eq!(start.add_period(&days), end);
+        assert_eq!(start.add_period(&weeks), end);
+    }
+
+    #[test]
+    fn until_end_of_month() {
+        let chronology = load_chronology("UTC");
+        // The day is clamped to the end of February, so it's one month and a day.
+        let start = date_time(&chronology, (2024, 1, 31), (0, 0, 0));
+        let end = date_time(&chronology, (2024, 3, 1), (0, 0, 0));
+        let period = start
+            .until(&end, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                months: 1,
+                days: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), end);
+
+        // Backwards, the result is negative.
+        let period = end
+            .until(&start, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                months: -1,
+                days: -1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(end.add_period(&period), start);
+        assert_eq!(
+            start.until(&start, Precision::Years, Precision::Nanoseconds),
+            Some(Period::default())
+        );
+    }
+
+    #[test]
+    fn until_across_leap_second() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2016, 12, 31), (12, 0, 0));
+        let end = date_time(&chronology, (2017, 1, 1), (12, 0, 0));
+        assert_eq!(
+            start.until(&end, Precision::Years, Precision::Nanoseconds),
+            Some(Period {
+                days: 1,
+                ..Default::default()
+            })
+        );
+        let period = start
+            .until(&end, Precision::Hours, Precision::Seconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                hours: 24,
+                seconds: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), end);
+
+        let leap_second = date_time(&chronology, (2016, 12, 31), (23, 59, 60));
+        let period = start
+            .until(&leap_second, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                hours: 12,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), leap_second);
+    }
+
+    #[test]
+    fn until_with_subseconds() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2000, 1, 1), (0, 0, 0));
+        let end = start.add_nanoseconds(-1_001_002_003);
+        let period = start
+            .until(&end, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(
+            period,
+            Period {
+                seconds: -1,
+                milliseconds: -1,
+                microseconds: -2,
+                nanoseconds: -3,
+                ..Default::default()
+            }
+        );
+        assert_eq!(start.add_period(&period), end);
+        assert_eq!(
+            start.until(&end, Precision::Years, Precision::Milliseconds),
+            Some(Period {
+                seconds: -1,
+                milliseconds: -1,
+                ..Default::default()
+            })
+        );
+
+        // Ten thousand years of nanoseconds don't fit in an i64.
+        let first = date_time(&chronology, (0, 1, 1), (0, 0, 0));
+        let last = date_time(&chronology, (9999, 12, 31), (23, 59, 59));
+        assert!(first
+            .until(&last, Precision::Nanoseconds, Precision::Nanoseconds)
+            .is_none());
+        let period = first
+            .until(&last, Precision::Years, Precision::Nanoseconds)
+            .unwrap();
+        assert_eq!(period.years, 9999);
+        assert_eq!(first.add_period(&period), last);
+    }
+
+    #[test]
+    fn add_period_out_of_range() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2024, 1, 31), (12, 0, 0));
+        // These are whole multiples of the 400-year cycle, which used to wrap around to the
+        // start instead of overflowing.
+        for period in [
+            Period {
+                years: 25_600,
+                ..Default::default()
+            },
+            Period {
+                months: 786_432,
+                ..Default::default()
+            },
+            Period {
+                months: -786_432,
+                ..Default::default()
+            },
+        ] {
+            assert_eq!(start.checked_add_period(&period), None);
+            for policy in [CarryPolicy::Constrain, CarryPolicy::Reject] {
+                assert_eq!(start.checked_add_period_with(&period, policy), None);
+            }
+        }
+
+        let first = date_time(&chronology, (0, 1, 1), (0, 0, 0));
+        let last = date_time(&chronology, (9999, 12, 1), (0, 0, 0));
+        let months = Period {
+            months: 9999 * 12 + 11,
+            ..Default::default()
+        };
+        assert_eq!(first.checked_add_period(&months), Some(last.clone()));
+        assert_eq!(last.checked_add_period(&months), None);
+        assert!(start.checked_add_months(786_432).is_none());
+        assert!(start.checked_add_years(25_600).is_none());
+    }
+
+    #[test]
+    fn duration_since() {
+        let chronology = load_chronology("UTC");
+        let start = date_time(&chronology, (2016, 12, 31), (23, 59, 59));
+        let end = date_time(&chronology, (2017, 1, 1), (0, 0, 0));
+        assert_eq!(
+            end.duration_since(&start).num_ticks(),
+            2 * NANOSECONDS_PER_SECOND
+        );
+        assert_eq!(
+            start.duration_since(&end).num_ticks(),
+            -2 * NANOSECONDS_PER_SECOND
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use crate::iso8601::DateTime;
+use crate::InstantNs128;
+
+/// How the spans of time denoted by two DateTimes relate, see [DateTime::relate]. A DateTime
+/// denotes the whole unit of its precision, so `2024-05` contains `2024-05-17`.
+///
+/// This is a partial order: a DateTime is [Before](RangeRelation::Before) or
+/// [After](RangeRelation::After) another only if their spans don't overlap. Spans of calendar
+/// units are either nested or disjoint, except weeks, which can overlap months and years.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum RangeRelation {
+    /// The span ends at or before the start of the other one.
+    Before,
+    /// The span starts at or after the end of the other one.
+    After,
+    /// The spans are the same, e.g. a DateTime and its truncation to its own precision.
+    Equal,
+    /// The span contains the other one and is longer.
+    Contains,
+    /// The span is contained in the other one and is shorter.
+    ContainedBy,
+    /// The spans overlap but neither contains the other, e.g. a week and a month.
+    Overlaps,
+}
+
+impl DateTime {
+    /// Returns the span of time denoted by the DateTime given its precision, from the start of
+    /// the unit of its precision that contains it to the start of the next one. A DateTime of
+    /// 2024-05 with a precision of months spans the whole of May, and one with a precision of
+    /// seconds spans a second, which is the leap second for 23:59:60.
+    pub fn as_range(&self) -> Range<InstantNs128> {
+        let start = self.truncated_point(self.precision());
+        let end = self.next_point(self.precision(), start);
+        self.instant_at_point(start)..self.instant_at_point(end)
+    }
+
+    /// Returns true if the span of the other DateTime is within the span of this one, see
+    /// [DateTime::as_range].
+    pub fn contains(&self, other: &DateTime) -> bool {
+        matches!(
+            self.relate(other),
+            RangeRelation::Equal | RangeRelation::Contains
+        )
+    }
+
+    /// Returns true if the spans of the DateTimes have any time in common, see
+    /// [DateTime::as_range].
+    pub fn overlaps(&self, other: &DateTime) -> bool {
+        !matches!(
+            self.relate(other),
+            RangeRelation::Before | RangeRelation::After
+        )
+    }
+
+    /// Returns how the span of this DateTime relates to the span of the other one, see
+    /// [DateTime::as_range] and [RangeRelation]. DateTimes of different chronologies are
+    /// compared by the instants they denote.
+    pub fn relate(&self, other: &DateTime) -> RangeRelation {
+        let range = self.as_range();
+        let other_range = other.as_range();
+        let (start, end) = (
+            range.start.ticks_since_epoch(),
+            range.end.ticks_since_epoch(),
+        );
+        let (other_start, other_end) = (
+            other_range.start.ticks_since_epoch(),
+            other_range.end.ticks_since_epoch(),
+        );
+        if end <= other_start {
+            RangeRelation::Before
+        } else if other_end <= start {
+            RangeRelation::After
+        } else if start == other_start && end == other_end {
+            RangeRelation::Equal
+        } else if start <= other_start && other_end <= end {
+            RangeRelation::Contains
+        } else if other_start <= start && end <= other_end {
+            RangeRelation::ContainedBy
+        } else {
+            RangeRelation::Overlaps
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::{load_chronology, Precision};
+    use crate::Duration;
+    use crate::Nanoseconds;
+
+    #[test]
+    fn as_range() {
+        let chronology = load_chronology("UTC");
+        let date = |year, month, day| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(month)
+                .day(day)
+                .build()
+        };
+        let month = date(2024, 5, 17).with_precision(Precision::Months).unwrap();
+        assert_eq!(
+            month.as_range(),
+            date(2024, 5, 1).instant()..date(2024, 6, 1).instant()
+        );
+        assert_eq!(
+            date(2024, 5, 17).as_range(),
+            date(2024, 5, 17).instant()..date(2024, 5, 18).instant()
+        );
+
+        // The last day of 2016 had a leap second.
+        let range = date(2016, 12, 31).as_range();
+        assert_eq!(
+            range.end.ticks_since_epoch() - range.start.ticks_since_epoch(),
+            86_401_000_000_000
+        );
+        let leap_second = DateTime::builder()
+            .chronology(&chronology)
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60)
+            .build();
+        assert_eq!(
+            leap_second.as_range(),
+            leap_second.instant()..date(2017, 1, 1).instant()
+        );
+
+        // The end of the last day is after the range of DateTime.
+        let range = date(9999, 12, 31).as_range();
+        assert_eq!(
+            range.end,
+            range.start + Duration::<i128, Nanoseconds>::new(86_400_000_000_000)
+        );
+    }
+
+    #[test]
+    fn relate() {
+        let chronology = load_chronology("UTC");
+        let date = |year, month, day| {
+            DateTime::builder()
+                .chronology(&chronology)
+                .year(year)
+                .month(month)
+                .day(day)
+                .build()
+        };
+        let may = date(2024, 5, 17).with_precision(Precision::Months).unwrap();
+        let june = date(2024, 6, 1).with_precision(Precision::Months).unwrap();
+        let day = date(2024, 5, 17);
+        let noon = DateTime::builder()
+            .chronology(&chronology)
+            .year(2024)
+            .month(5)
+            .day(17)
+            .hour(12)
+            .build();
+
+        assert_eq!(may.relate(&day), RangeRelation::Contains);
+        assert_eq!(day.relate(&may), RangeRelation::ContainedBy);
+        assert_eq!(may.relate(&noon), RangeRelation::Contains);
+        assert_eq!(day.relate(&noon), RangeRelation::Contains);
+        assert_eq!(may.relate(&june), RangeRelation::Before);
+        assert_eq!(june.relate(&may), RangeRelation::After);
+        assert_eq!(june.relate(&day), RangeRelation::After);
+        assert_eq!(may.relate(&may.clone()), RangeRelation::Equal);
+
+        // A month and the first day of it with a precision of months are the same span.
+        let first = date(2024, 5, 1).with_precision(Precision::Months).unwrap();
+        assert_eq!(may.relate(&first), RangeRelation::Equal);
+
+        // The week from Monday 2024-04-29 is partly in April and partly in May.
+        let week = date(2024, 5, 1).with_precision(Precision::Weeks).unwrap();
+        assert_eq!(week.relate(&may), RangeRelation::Overlaps);
+        assert_eq!(may.relate(&week), RangeRelation::Overlaps);
+
+        assert!(may.contains(&day));
+        assert!(may.contains(&first));
+        assert!(!day.contains(&may));
+        assert!(!may.contains(&week));
+        assert!(may.overlaps(&week));
+        assert!(may.overlaps(&day));
+        assert!(day.overlaps(&may));
+        assert!(!may.overlaps(&june));
+    }
+}
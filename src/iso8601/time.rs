@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::iso8601::date_time_builder::Error;
+use crate::iso8601::parse_error::{parse_digits, ParseError};
+use crate::iso8601::{
+    HOURS_PER_DAY, MINUTES_PER_HOUR, SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE,
+};
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+const NANOSECONDS_PER_MILLISECOND: u32 = 1_000_000;
+const NANOSECONDS_PER_MICROSECOND: u32 = 1_000;
+
+/// A time of day without a date or a time zone, e.g. the opening time of a store, with nanosecond
+/// resolution. It can be the leap second 23:59:60, which only becomes a valid instant when
+/// combined with a date that has one, see [Date::at](crate::iso8601::Date::at).
+///
+/// Times are formatted and parsed in the ISO 8601 extended format, e.g. `13:45:30.123`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Time {
+    // Ordered so that the derived comparisons are correct.
+    second: u32,
+    nanosecond: u32,
+}
+
+impl Time {
+    pub const MIDNIGHT: Time = Time {
+        second: 0,
+        nanosecond: 0,
+    };
+
+    /// Returns the time with the given hour from 0 to 23, minute from 0 to 59 and second from
+    /// 0 to 59, or 60 for a leap second at 23:59.
+    pub fn from_hms(hour: u8, minute: u8, second: u8) -> Result<Time, Error> {
+        Time::from_hms_nano(hour, minute, second, 0)
+    }
+
+    /// Like [Time::from_hms], with the nanoseconds since the start of the second.
+    pub fn from_hms_nano(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Result<Time, Error> {
+        let is_last_minute = hour == HOURS_PER_DAY - 1 && minute == MINUTES_PER_HOUR - 1;
+        if hour >= HOURS_PER_DAY
+            || minute >= MINUTES_PER_HOUR
+            || second > SECONDS_PER_MINUTE
+            || (second == SECONDS_PER_MINUTE && !is_last_minute)
+            || nanosecond >= NANOSECONDS_PER_SECOND
+        {
+            return Err(Error::InvalidDateTime);
+        }
+        Ok(Time {
+            second: hour as u32 * SECONDS_PER_HOUR as u32
+                + minute as u32 * SECONDS_PER_MINUTE as u32
+                + second as u32,
+            nanosecond,
+        })
+    }
+
+    pub(super) fn from_second_of_day(second: u32, nanosecond: u32) -> Time {
+        Time { second, nanosecond }
+    }
+
+    pub fn hour(&self) -> u8 {
+        (self.second / SECONDS_PER_HOUR as u32).min(HOURS_PER_DAY as u32 - 1) as u8
+    }
+
+    pub fn minute(&self) -> u8 {
+        // The leap second is in the last minute of the day, so it has to be clamped.
+        let minute = self.second % SECONDS_PER_HOUR as u32 / SECONDS_PER_MINUTE as u32;
+        if self.is_leap_second() {
+            MINUTES_PER_HOUR - 1
+        } else {
+            minute as u8
+        }
+    }
+
+    pub fn second(&self) -> u8 {
+        if self.is_leap_second() {
+            SECONDS_PER_MINUTE
+        } else {
+            (self.second % SECONDS_PER_MINUTE as u32) as u8
+        }
+    }
+
+    /// The millisecond of the second, like
+    /// [DateTime::millisecond](crate::iso8601::DateTime::millisecond).
+    pub fn millisecond(&self) -> u16 {
+        (self.nanosecond / NANOSECONDS_PER_MILLISECOND) as u16
+    }
+
+    /// The microsecond of the millisecond, like
+    /// [DateTime::microsecond](crate::iso8601::DateTime::microsecond).
+    pub fn microsecond(&self) -> u16 {
+        (self.nanosecond / NANOSECONDS_PER_MICROSECOND % 1_000) as u16
+    }
+
+    /// The nanosecond of the microsecond, like
+    /// [DateTime::nanosecond](crate::iso8601::DateTime::nanosecond).
+    pub fn nanosecond(&self) -> u16 {
+        (self.nanosecond % NANOSECONDS_PER_MICROSECOND) as u16
+    }
+
+    /// The number of seconds since midnight, which is 86,400 for the leap second.
+    pub fn second_of_day(&self) -> u32 {
+        self.second
+    }
+
+    /// The number of nanoseconds since the start of the second.
+    pub fn subsecond_nanoseconds(&self) -> u32 {
+        self.nanosecond
+    }
+
+    pub fn is_leap_second(&self) -> bool {
+        self.second >= SECONDS_PER_DAY
+    }
+
+    /// Returns the time the given number of seconds later, or earlier if negative, wrapping
+    /// around midnight, and the number of days it wrapped. Days are taken to be 86,400 seconds
+    /// long since a Time doesn't know whether its day has a leap second, and the leap second
+    /// itself counts as 23:59:59.
+    pub fn overflowing_add_seconds(&self, seconds: i64) -> (Time, i64) {
+        self.overflowing_add(seconds as i128 * NANOSECONDS_PER_SECOND as i128)
+    }
+
+    /// Like [Time::overflowing_add_seconds], for nanoseconds.
+    pub fn overflowing_add_nanoseconds(&self, nanoseconds: i64) -> (Time, i64) {
+        self.overflowing_add(nanoseconds as i128)
+    }
+
+    /// Like [Time::overflowing_add_seconds], without the number of days.
+    pub fn wrapping_add_seconds(&self, seconds: i64) -> Time {
+        self.overflowing_add_seconds(seconds).0
+    }
+
+    /// Returns the number of nanoseconds from the other time to this one on the same day of
+    /// 86,400 seconds, which is negative if the other time is later. The leap second counts as
+    /// 23:59:59, like in [Time::overflowing_add_seconds].
+    pub fn nanoseconds_since(&self, other: &Time) -> i64 {
+        (self.nanoseconds_of_day() - other.nanoseconds_of_day()) as i64
+    }
+
+    fn overflowing_add(&self, nanoseconds: i128) -> (Time, i64) {
+        let nanoseconds_per_day = SECONDS_PER_DAY as i128 * NANOSECONDS_PER_SECOND as i128;
+        let nanoseconds = self.nanoseconds_of_day() + nanoseconds;
+        let days = nanoseconds.div_euclid(nanoseconds_per_day);
+        let nanoseconds = nanoseconds.rem_euclid(nanoseconds_per_day);
+        let time = Time {
+            second: (nanoseconds / NANOSECONDS_PER_SECOND as i128) as u32,
+            nanosecond: (nanoseconds % NANOSECONDS_PER_SECOND as i128) as u32,
+        };
+        (time, days as i64)
+    }
+
+    fn nanoseconds_of_day(&self) -> i128 {
+        let second = self.second.min(SECONDS_PER_DAY - 1);
+        second as i128 * NANOSECONDS_PER_SECOND as i128 + self.nanosecond as i128
+    }
+}
+
+impl Display for Time {
+    /// Formats the time as `hh:mm:ss`, followed by the fraction of the second in groups of three
+    /// digits if it isn't zero.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )?;
+        if self.nanosecond == 0 {
+            Ok(())
+        } else if self.nanosecond % NANOSECONDS_PER_MILLISECOND == 0 {
+            write!(f, ".{:03}", self.millisecond())
+        } else if self.nanosecond % NANOSECONDS_PER_MICROSECOND == 0 {
+            write!(f, ".{:06}", self.nanosecond / NANOSECONDS_PER_MICROSECOND)
+        } else {
+            write!(f, ".{:09}", self.nanosecond)
+        }
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseError;
+
+    /// Parses a time in the ISO 8601 extended format, `hh:mm`, `hh:mm:ss` or `hh:mm:ss.s` with
+    /// one to nine digits of a fraction of the second, which can also be separated by a comma.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = ParseError::InvalidFormat("hh:mm[:ss[.s]]");
+        let bytes = s.as_bytes();
+        if bytes.len() < 5 || bytes[2] != b':' || (bytes.len() > 5 && bytes[5] != b':') {
+            return Err(format);
+        }
+        let hour = parse_digits(&bytes[0..2]).ok_or(format)?;
+        let minute = parse_digits(&bytes[3..5]).ok_or(format)?;
+        let (second, nanosecond) = match bytes.len() {
+            5 => (0, 0),
+            8 => (parse_digits(&bytes[6..8]).ok_or(format)?, 0),
+            10..=18 if bytes[8] == b'.' || bytes[8] == b',' => {
+                let second = parse_digits(&bytes[6..8]).ok_or(format)?;
+                let fraction = &bytes[9..];
+                let scale = 10_u32.pow(9 - fraction.len() as u32);
+                (second, parse_digits(fraction).ok_or(format)? * scale)
+            }
+            _ => return Err(format),
+        };
+        Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanosecond)
+            .map_err(|_| ParseError::InvalidValue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hms() {
+        let time = Time::from_hms_nano(13, 45, 30, 123_456_789).unwrap();
+        assert_eq!((time.hour(), time.minute(), time.second()), (13, 45, 30));
+        assert_eq!(
+            (time.millisecond(), time.microsecond(), time.nanosecond()),
+            (123, 456, 789)
+        );
+        assert_eq!(time.second_of_day(), 49_530);
+        assert_eq!(time.subsecond_nanoseconds(), 123_456_789);
+        assert!(!time.is_leap_second());
+
+        let leap_second = Time::from_hms(23, 59, 60).unwrap();
+        assert!(leap_second.is_leap_second());
+        assert_eq!(
+            (
+                leap_second.hour(),
+                leap_second.minute(),
+                leap_second.second()
+            ),
+            (23, 59, 60)
+        );
+        assert!(leap_second > Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap());
+
+        assert_eq!(Time::from_hms(0, 0, 0), Ok(Time::MIDNIGHT));
+        assert_eq!(Time::from_hms(24, 0, 0), Err(Error::InvalidDateTime));
+        assert_eq!(Time::from_hms(12, 60, 0), Err(Error::InvalidDateTime));
+        assert_eq!(Time::from_hms(12, 59, 60), Err(Error::InvalidDateTime));
+        assert_eq!(Time::from_hms(23, 59, 61), Err(Error::InvalidDateTime));
+        assert_eq!(
+            Time::from_hms_nano(12, 0, 0, 1_000_000_000),
+            Err(Error::InvalidDateTime)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let time = Time::from_hms(22, 30, 0).unwrap();
+        assert_eq!(
+            time.overflowing_add_seconds(3_600),
+            (Time::from_hms(23, 30, 0).unwrap(), 0)
+        );
+        assert_eq!(
+            time.overflowing_add_seconds(7_200),
+            (Time::from_hms(0, 30, 0).unwrap(), 1)
+        );
+        assert_eq!(
+            time.overflowing_add_seconds(-2 * 86_400 - 1),
+            (Time::from_hms(22, 29, 59).unwrap(), -2)
+        );
+        assert_eq!(
+            Time::MIDNIGHT.overflowing_add_nanoseconds(-1),
+            (Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(), -1)
+        );
+        assert_eq!(
+            Time::from_hms(23, 59, 60)
+                .unwrap()
+                .overflowing_add_seconds(1),
+            (Time::MIDNIGHT, 1)
+        );
+        assert_eq!(time.wrapping_add_seconds(i64::MAX).second_of_day(), 50_407);
+        assert_eq!(
+            time.nanoseconds_since(&Time::from_hms(22, 0, 0).unwrap()),
+            1_800_000_000_000
+        );
+        assert_eq!(Time::MIDNIGHT.nanoseconds_since(&time), -81_000_000_000_000);
+    }
+
+    #[test]
+    fn format_and_parse() {
+        for (s, time) in [
+            ("00:00:00", Time::MIDNIGHT),
+            ("13:45:30", Time::from_hms(13, 45, 30).unwrap()),
+            (
+                "13:45:30.100",
+                Time::from_hms_nano(13, 45, 30, 100_000_000).unwrap(),
+            ),
+            (
+                "13:45:30.000100",
+                Time::from_hms_nano(13, 45, 30, 100_000).unwrap(),
+            ),
+            (
+                "13:45:30.000000100",
+                Time::from_hms_nano(13, 45, 30, 100).unwrap(),
+            ),
+            ("23:59:60", Time::from_hms(23, 59, 60).unwrap()),
+        ] {
+            assert_eq!(time.to_string(), s);
+            assert_eq!(s.parse(), Ok(time));
+        }
+        assert_eq!("13:45".parse(), Ok(Time::from_hms(13, 45, 0).unwrap()));
+        assert_eq!(
+            "13:45:30,5".parse(),
+            Ok(Time::from_hms_nano(13, 45, 30, 500_000_000).unwrap())
+        );
+        assert_eq!("24:00".parse::<Time>(), Err(ParseError::InvalidValue));
+        assert_eq!("12:00:60".parse::<Time>(), Err(ParseError::InvalidValue));
+        for s in [
+            "1:45",
+            "13:45:3",
+            "13:45:30.",
+            "13:45:30.1234567890",
+            "134530",
+            "13:45Z",
+        ] {
+            assert_eq!(
+                s.parse::<Time>(),
+                Err(ParseError::InvalidFormat("hh:mm[:ss[.s]]")),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use crate::gregorian_normalized_date::GregorianNormalizedDate;
+use crate::iso8601::precision::Precision;
+use crate::iso8601::{DateTime, Weekday, SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+use crate::InstantNs128;
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+// A position in time as the number of days since the Unix epoch, the second of the day and the
+// nanosecond of the second, like DateTime::to_fixed_day. Unlike a DateTime it can be outside the
+// range 0000 to 9999, which is needed for the end of the last unit of the range.
+pub(super) type Point = (i32, u32, u32);
+
+impl DateTime {
+    /// Returns the start of the unit of time that contains this DateTime, e.g. the first day of
+    /// the month or the start of the minute. Weeks start on Monday as in ISO 8601, and decades,
+    /// centuries and millennia start on years that are multiples of 10, 100 and 1,000. The
+    /// precision of the result is the same as this one; use [DateTime::with_precision] to change
+    /// it as well.
+    ///
+    /// Returns None if the start is before 0000-01-01, which only happens for the week that
+    /// contains it.
+    pub fn truncate(&self, unit: Precision) -> Option<DateTime> {
+        self.at_point(self.truncated_point(unit))
+    }
+
+    /// Returns the start of the next unit of time, unless this DateTime is already at the start
+    /// of a unit. See [DateTime::truncate]. Returns None if the result is after 9999-12-31.
+    pub fn ceil(&self, unit: Precision) -> Option<DateTime> {
+        let start = self.truncated_point(unit);
+        if start == self.to_fixed_day() {
+            return self.at_point(start);
+        }
+        self.at_point(self.next_point(unit, start))
+    }
+
+    /// Returns the start of the nearest unit of time, or the start of the next one if this
+    /// DateTime is exactly halfway. See [DateTime::truncate]. The halfway point is found with
+    /// the actual length of the unit, so the last minute of a day with a leap second is rounded
+    /// up from 30.5 seconds rather than 30. Returns None if the result is out of range.
+    pub fn round(&self, unit: Precision) -> Option<DateTime> {
+        let point = self.to_fixed_day();
+        let start = self.truncated_point(unit);
+        if start == point {
+            return self.at_point(start);
+        }
+        let end = self.next_point(unit, start);
+        let elapsed = self.nanoseconds_between(start, point);
+        let length = self.nanoseconds_between(start, end);
+        if elapsed * 2 < length {
+            self.at_point(start)
+        } else {
+            self.at_point(end)
+        }
+    }
+
+    /// Truncates the DateTime to the given precision like [DateTime::truncate], and sets the
+    /// precision of the result to it.
+    pub fn with_precision(&self, precision: Precision) -> Option<DateTime> {
+        let (day, second, nanosecond) = self.truncated_point(precision);
+        DateTime::from_fixed_day(self.chronology(), precision, day, second, nanosecond)
+    }
+
+    pub(super) fn truncated_point(&self, unit: Precision) -> Point {
+        let (day, second, nanosecond) = self.to_fixed_day();
+        let year = self.year() as i32;
+        let hour = self.hour() as u32 * SECONDS_PER_HOUR as u32;
+        // The hour and minute are clamped, so that a leap second is in the last minute.
+        let minute = hour + self.minute() as u32 * SECONDS_PER_MINUTE as u32;
+        match unit {
+            Precision::Millennia => (first_day(year - year % 1_000, 1), 0, 0),
+            Precision::Centuries => (first_day(year - year % 100, 1), 0, 0),
+            Precision::Decades => (first_day(year - year % 10, 1), 0, 0),
+            Precision::Years => (first_day(year, 1), 0, 0),
+            Precision::Months => (first_day(year, self.month()), 0, 0),
+            Precision::Weeks => {
+                let weekday = Weekday::from_fixed_day(day);
+                (day - Weekday::Monday.days_until(weekday) as i32, 0, 0)
+            }
+            Precision::Days => (day, 0, 0),
+            Precision::Hours => (day, hour, 0),
+            Precision::Minutes => (day, minute, 0),
+            Precision::Seconds => (day, second, 0),
+            Precision::Milliseconds => (day, second, nanosecond - nanosecond % 1_000_000),
+            Precision::Microseconds => (day, second, nanosecond - nanosecond % 1_000),
+            Precision::Nanoseconds => (day, second, nanosecond),
+        }
+    }
+
+    // Returns the start of the unit after the one that starts at the given point.
+    pub(super) fn next_point(&self, unit: Precision, (day, second, nanosecond): Point) -> Point {
+        let year = self.year() as i32;
+        let next_second = |second: u32| {
+            if second < self.chronology().leap_seconds().day_length(day) {
+                (day, second, 0)
+            } else {
+                (day + 1, 0, 0)
+            }
+        };
+        let next_nanosecond = |nanoseconds: u32| {
+            if nanosecond + nanoseconds < NANOSECONDS_PER_SECOND {
+                (day, second, nanosecond + nanoseconds)
+            } else {
+                next_second(second + 1)
+            }
+        };
+        match unit {
+            Precision::Millennia => (first_day(year - year % 1_000 + 1_000, 1), 0, 0),
+            Precision::Centuries => (first_day(year - year % 100 + 100, 1), 0, 0),
+            Precision::Decades => (first_day(year - year % 10 + 10, 1), 0, 0),
+            Precision::Years => (first_day(year + 1, 1), 0, 0),
+            Precision::Months if self.month() == 12 => (first_day(year + 1, 1), 0, 0),
+            Precision::Months => (first_day(year, self.month() + 1), 0, 0),
+            Precision::Weeks => (day + 7, 0, 0),
+            Precision::Days => (day + 1, 0, 0),
+            // The last hour and minute of the day end at the end of the day, however long it is.
+            Precision::Hours if second + SECONDS_PER_HOUR as u32 >= SECONDS_PER_DAY => {
+                (day + 1, 0, 0)
+            }
+            Precision::Hours => (day, second + SECONDS_PER_HOUR as u32, 0),
+            Precision::Minutes if second + SECONDS_PER_MINUTE as u32 >= SECONDS_PER_DAY => {
+                (day + 1, 0, 0)
+            }
+            Precision::Minutes => (day, second + SECONDS_PER_MINUTE as u32, 0),
+            Precision::Seconds => next_second(second + 1),
+            Precision::Milliseconds => next_nanosecond(1_000_000),
+            Precision::Microseconds => next_nanosecond(1_000),
+            Precision::Nanoseconds => next_nanosecond(1),
+        }
+    }
+
+    fn nanoseconds_between(&self, from: Point, to: Point) -> i128 {
+        self.instant_at_point(to).ticks_since_epoch()
+            - self.instant_at_point(from).ticks_since_epoch()
+    }
+
+    pub(super) fn instant_at_point(&self, (day, second, nanosecond): Point) -> InstantNs128 {
+        let gnd = GregorianNormalizedDate::from_day(day)
+            .expect("a point within a few thousand years is a valid date");
+        let seconds = self.to_second_instant(gnd, second).ticks_since_epoch();
+        InstantNs128::from_seconds_and_nanoseconds(seconds, nanosecond)
+            .expect("a point within a few thousand years is within the range of InstantNs128")
+    }
+
+    fn at_point(&self, (day, second, nanosecond): Point) -> Option<DateTime> {
+        DateTime::from_fixed_day(self.chronology(), self.precision(), day, second, nanosecond)
+    }
+}
+
+// Returns the number of days since the Unix epoch of the first day of the month.
+fn first_day(year: i32, month: u8) -> i32 {
+    GregorianNormalizedDate::from_date(year, month, 1)
+        .expect("the first day of a month within a few thousand years is a valid date")
+        .to_day()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso8601::{load_chronology, Chronology};
+
+    fn date_time(
+        chronology: &Chronology,
+        (year, month, day): (u16, u8, u8),
+        (hour, minute, second): (u8, u8, u8),
+    ) -> DateTime {
+        DateTime::builder()
+            .chronology(chronology)
+            .year(year)
+            .month(month)
+            .day(day)
+            .hour(hour)
+            .minute(minute)
+            .second(second)
+            .build()
+    }
+
+    #[test]
+    fn truncate() {
+        let chronology = load_chronology("UTC");
+        let at = |date, time| date_time(&chronology, date, time);
+        // A Friday.
+        let date_time = at((2024, 5, 17), (13, 45, 30));
+        let midnight = (0, 0, 0);
+        for (unit, expected) in [
+            (Precision::Millennia, at((2000, 1, 1), midnight)),
+            (Precision::Centuries, at((2000, 1, 1), midnight)),
+            (Precision::Decades, at((2020, 1, 1), midnight)),
+            (Precision::Years, at((2024, 1, 1), midnight)),
+            (Precision::Months, at((2024, 5, 1), midnight)),
+            (Precision::Weeks, at((2024, 5, 13), midnight)),
+            (Precision::Days, at((2024, 5, 17), midnight)),
+            (Precision::Hours, at((2024, 5, 17), (13, 0, 0))),
+            (Precision::Minutes, at((2024, 5, 17), (13, 45, 0))),
+            (Precision::Seconds, date_time.clone()),
+            (Precision::Nanoseconds, date_time.clone()),
+        ] {
+            assert_eq!(date_time.truncate(unit), Some(expected), "{:?}", unit);
+        }
+
+        // Weeks can start in the previous year.
+        assert_eq!(
+            at((2021, 1, 1), midnight).truncate(Precision::Weeks),
+            Some(at((2020, 12, 28), midnight))
+        );
+        assert_eq!(at((0, 1, 1), midnight).truncate(Precision::Weeks), None);
+
+        // The leap second is part of the last minute and hour.
+        let leap_second = at((2016, 12, 31), (23, 59, 60));
+        assert_eq!(
+            leap_second.truncate(Precision::Minutes),
+            Some(at((2016, 12, 31), (23, 59, 0)))
+        );
+        assert_eq!(
+            leap_second.truncate(Precision::Hours),
+            Some(at((2016, 12, 31), (23, 0, 0)))
+        );
+        assert_eq!(leap_second.truncate(Precision::Seconds), Some(leap_second));
+    }
+
+    #[test]
+    fn truncate_subseconds() {
+        let date_time = DateTime::builder()
+            .year(2024)
+            .month(5)
+            .day(17)
+            .hour(13)
+            .minute(45)
+            .second(30)
+            .millisecond(123)
+            .microsecond(456)
+            .nanosecond(789)
+            .build();
+        let truncated = date_time.truncate(Precision::Milliseconds).unwrap();
+        assert_eq!(truncated.precision(), Precision::Nanoseconds);
+        assert_eq!(
+            (
+                truncated.millisecond(),
+                truncated.microsecond(),
+                truncated.nanosecond()
+            ),
+            (123, 0, 0)
+        );
+        let truncated = date_time.truncate(Precision::Microseconds).unwrap();
+        assert_eq!(
+            (
+                truncated.millisecond(),
+                truncated.microsecond(),
+                truncated.nanosecond()
+            ),
+            (123, 456, 0)
+        );
+
+        let with_precision = date_time.with_precision(Precision::Milliseconds).unwrap();
+        assert_eq!(with_precision.precision(), Precision::Milliseconds);
+        assert_eq!(
+            with_precision.cmp_instant(&date_time.truncate(Precision::Milliseconds).unwrap()),
+            std::cmp::Ordering::Equal
+        );
+        let with_precision = date_time.with_precision(Precision::Days).unwrap();
+        assert_eq!(with_precision.precision(), Precision::Days);
+        assert_eq!(
+            with_precision,
+            DateTime::builder()
+                .chronology(date_time.chronology())
+                .year(2024)
+                .month(5)
+                .day(17)
+                .build()
+        );
+
+        assert_eq!(
+            date_time.ceil(Precision::Microseconds),
+            Some(date_time.add_nanoseconds(211))
+        );
+        assert_eq!(
+            date_time.round(Precision::Milliseconds),
+            Some(date_time.add_nanoseconds(-456_789))
+        );
+        assert_eq!(
+            date_time.round(Precision::Microseconds),
+            Some(date_time.add_nanoseconds(211))
+        );
+    }
+
+    #[test]
+    fn ceil() {
+        let chronology = load_chronology("UTC");
+        let at = |date, time| date_time(&chronology, date, time);
+        let midnight = (0, 0, 0);
+        let date_time = at((2024, 5, 17), (13, 45, 30));
+        assert_eq!(
+            date_time.ceil(Precision::Centuries),
+            Some(at((2100, 1, 1), midnight))
+        );
+        assert_eq!(
+            date_time.ceil(Precision::Months),
+            Some(at((2024, 6, 1), midnight))
+        );
+        assert_eq!(
+            date_time.ceil(Precision::Weeks),
+            Some(at((2024, 5, 20), midnight))
+        );
+        assert_eq!(
+            at((2024, 12, 17), midnight).ceil(Precision::Months),
+            Some(at((2025, 1, 1), midnight))
+        );
+        assert_eq!(
+            at((2024, 5, 1), midnight).ceil(Precision::Months),
+            Some(at((2024, 5, 1), midnight))
+        );
+        assert_eq!(at((9999, 12, 31), (0, 0, 1)).ceil(Precision::Days), None);
+
+        // The last minute of a day with a leap second ends after the leap second.
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 59)).ceil(Precision::Seconds),
+            Some(at((2016, 12, 31), (23, 59, 59)))
+        );
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 1)).ceil(Precision::Minutes),
+            Some(at((2017, 1, 1), midnight))
+        );
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 60)).ceil(Precision::Hours),
+            Some(at((2017, 1, 1), midnight))
+        );
+    }
+
+    #[test]
+    fn round() {
+        let chronology = load_chronology("UTC");
+        let at = |date, time| date_time(&chronology, date, time);
+        let midnight = (0, 0, 0);
+        assert_eq!(
+            at((2024, 7, 1), midnight).round(Precision::Years),
+            Some(at((2024, 1, 1), midnight))
+        );
+        // 2024 is a leap year, so July 2 is exactly halfway.
+        assert_eq!(
+            at((2024, 7, 2), midnight).round(Precision::Years),
+            Some(at((2025, 1, 1), midnight))
+        );
+        assert_eq!(
+            at((2024, 5, 16), (11, 59, 59)).round(Precision::Months),
+            Some(at((2024, 5, 1), midnight))
+        );
+        // May has 31 days, so noon on May 16 is exactly halfway.
+        assert_eq!(
+            at((2024, 5, 16), (12, 0, 0)).round(Precision::Months),
+            Some(at((2024, 6, 1), midnight))
+        );
+        assert_eq!(
+            at((2024, 5, 16), (11, 59, 59)).round(Precision::Weeks),
+            Some(at((2024, 5, 13), midnight))
+        );
+        assert_eq!(
+            at((9999, 12, 31), (11, 59, 59)).round(Precision::Days),
+            Some(at((9999, 12, 31), midnight))
+        );
+        assert_eq!(at((9999, 12, 31), (12, 0, 0)).round(Precision::Days), None);
+
+        // The last minute of a day with a leap second is 61 seconds long.
+        assert_eq!(
+            at((2016, 12, 30), (23, 59, 30)).round(Precision::Minutes),
+            Some(at((2016, 12, 31), midnight))
+        );
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 30)).round(Precision::Minutes),
+            Some(at((2016, 12, 31), (23, 59, 0)))
+        );
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 31)).round(Precision::Minutes),
+            Some(at((2017, 1, 1), midnight))
+        );
+        assert_eq!(
+            at((2016, 12, 31), (23, 59, 60)).round(Precision::Seconds),
+            Some(at((2016, 12, 31), (23, 59, 60)))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::ops::{Add, Sub};
+
+use crate::iso8601::DAYS_PER_WEEK;
+
+/// A day of the week. Weeks start on Monday as in ISO 8601, so Monday is the least and Sunday
+/// the greatest. Adding or subtracting days wraps around the week.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Every day of the week from Monday to Sunday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the weekday with the given ISO 8601 number, from 1 for Monday to 7 for Sunday.
+    pub fn from_number(number: u8) -> Option<Weekday> {
+        (1..=DAYS_PER_WEEK)
+            .contains(&number)
+            .then(|| Self::ALL[(number - 1) as usize])
+    }
+
+    /// The ISO 8601 number of the weekday, from 1 for Monday to 7 for Sunday.
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    pub fn succ(self) -> Weekday {
+        self + 1
+    }
+
+    pub fn pred(self) -> Weekday {
+        self - 1
+    }
+
+    /// Returns the number of days from this weekday to the next given one, from 0 to 6.
+    pub fn days_until(self, other: Weekday) -> u8 {
+        (other.number() + DAYS_PER_WEEK - self.number()) % DAYS_PER_WEEK
+    }
+
+    /// Iterates over the days of the week starting with this one, e.g. to start weeks on Sunday.
+    pub fn iter_from(self) -> impl Iterator<Item = Weekday> {
+        (0..DAYS_PER_WEEK as i64).map(move |days| self + days)
+    }
+
+    // Returns the weekday of the given number of days since the Unix epoch, which was a Thursday.
+    pub(super) fn from_fixed_day(day: i32) -> Weekday {
+        Weekday::Thursday + day as i64
+    }
+}
+
+impl Add<i64> for Weekday {
+    type Output = Weekday;
+
+    fn add(self, days: i64) -> Self::Output {
+        let days = days % DAYS_PER_WEEK as i64;
+        let index = (self.number() as i64 - 1 + days).rem_euclid(DAYS_PER_WEEK as i64);
+        Self::ALL[index as usize]
+    }
+}
+
+impl Sub<i64> for Weekday {
+    type Output = Weekday;
+
+    fn sub(self, days: i64) -> Self::Output {
+        // The remainder of i64::MIN can't be negated, so reduce it first.
+        self + -(days % DAYS_PER_WEEK as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        for (index, weekday) in Weekday::ALL.into_iter().enumerate() {
+            assert_eq!(weekday.number() as usize, index + 1);
+            assert_eq!(Weekday::from_number(weekday.number()), Some(weekday));
+        }
+        assert_eq!(Weekday::from_number(0), None);
+        assert_eq!(Weekday::from_number(8), None);
+        assert!(Weekday::Monday < Weekday::Sunday);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(Weekday::Friday + 3, Weekday::Monday);
+        assert_eq!(Weekday::Friday - 12, Weekday::Sunday);
+        assert_eq!(Weekday::Friday + -12, Weekday::Sunday);
+        assert_eq!(Weekday::Monday + i64::MAX, Weekday::Monday);
+        assert_eq!(Weekday::Monday - i64::MIN, Weekday::Tuesday);
+        assert_eq!(Weekday::Friday.days_until(Weekday::Monday), 3);
+        assert_eq!(Weekday::Friday.days_until(Weekday::Friday), 0);
+        assert_eq!(Weekday::from_fixed_day(0), Weekday::Thursday);
+        assert_eq!(Weekday::from_fixed_day(-4), Weekday::Sunday);
+    }
+
+    #[test]
+    fn iter_from() {
+        let days: Vec<_> = Weekday::Saturday.iter_from().collect();
+        assert_eq!(
+            days,
+            vec![
+                Weekday::Saturday,
+                Weekday::Sunday,
+                Weekday::Monday,
+                Weekday::Tuesday,
+                Weekday::Wednesday,
+                Weekday::Thursday,
+                Weekday::Friday,
+            ]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use crate::instant::Tick;
 
-pub(crate) trait LeastCommonWidth<T: Tick>: Tick {
+/// The narrowest integer type that can represent all values of both Self and T. Used as the
+/// tick type of the result when adding or subtracting instants and durations of different tick
+/// types.
+pub trait LeastCommonWidth<T: Tick>: Tick {
     type Output: Tick;
     fn least_common_width(
         self,
@@ -11,6 +14,15 @@ pub(crate) trait LeastCommonWidth<T: Tick>: Tick {
     );
 }
 
+pub(crate) type CommonTick<T1, T2> = <T1 as LeastCommonWidth<T2>>::Output;
+
+impl<T: Tick> LeastCommonWidth<T> for T {
+    type Output = T;
+    fn least_common_width(self, other: T) -> (T, T) {
+        (self, other)
+    }
+}
+
 macro_rules! impl_least_common_width {
     ($Smaller:ty, $Bigger:ty) => {
         impl LeastCommonWidth<$Bigger> for $Smaller {
@@ -28,17 +40,6 @@ macro_rules! impl_least_common_width {
     };
 }
 
-macro_rules! impl_least_common_width_equal {
-    ($T:ty) => {
-        impl LeastCommonWidth<$T> for $T {
-            type Output = $T;
-            fn least_common_width(self, other: $T) -> ($T, $T) {
-                (self, other)
-            }
-        }
-    };
-}
-
 impl_least_common_width!(u8, u16);
 impl_least_common_width!(u8, u32);
 impl_least_common_width!(u8, u64);
@@ -76,14 +77,3 @@ impl_least_common_width!(i32, i64);
 impl_least_common_width!(i32, i128);
 
 impl_least_common_width!(i64, i128);
-
-impl_least_common_width_equal!(u8);
-impl_least_common_width_equal!(u16);
-impl_least_common_width_equal!(u32);
-impl_least_common_width_equal!(u64);
-impl_least_common_width_equal!(u128);
-impl_least_common_width_equal!(i8);
-impl_least_common_width_equal!(i16);
-impl_least_common_width_equal!(i32);
-impl_least_common_width_equal!(i64);
-impl_least_common_width_equal!(i128);
@@ -1,18 +1,45 @@
-pub use duration::{Duration, DurationNs128};
-pub use instant::{Instant, InstantNs128, InstantOutOfRange};
-pub use scale::{Nanoseconds, Scale};
+#[cfg(target_os = "linux")]
+pub use clock::TaiClock;
+pub use clock::{Clock, ClockError, MockClock, MonotonicClock, SystemClock};
+pub use duration::{Duration, DurationNs128, DurationOutOfRange};
+pub use dyn_duration::DynDuration;
+pub use dyn_instant::DynInstant;
+pub use gnss::{GnssSystem, GnssWeekTime};
+pub use instant::{
+    set_system_clock_leap_second_refresh_interval, set_system_clock_leap_seconds,
+    system_clock_leap_seconds, Instant, InstantNs128, InstantOutOfRange, SystemClockLeapSeconds,
+    SystemTimeConversionError,
+};
+pub use least_common_width::LeastCommonWidth;
+pub use ntp::NtpTimestamp;
+pub use packed_instant::{PackedInstant, PackedInstantNs64};
+pub use ptp::PtpTimestamp;
+pub use rounding::Rounding;
+pub use scale::{
+    Attoseconds, Deciseconds, FinerScale, Microseconds, Milliseconds, Nanoseconds, Picoseconds,
+    Scale, Seconds, Timebase,
+};
 
+mod clock;
 mod cursor;
 mod datetime;
 mod div_rem;
 mod duration;
+mod dyn_duration;
+mod dyn_instant;
+mod gnss;
 mod gregorian_normalized_date;
 mod instant;
 pub mod iso8601;
 mod least_common_width;
+mod ntp;
+mod packed_instant;
 mod period;
+mod ptp;
+mod rounding;
 mod scale;
 mod slice_cursor;
+mod wide;
 mod widen;
 mod zoneinfo;
 
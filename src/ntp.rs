@@ -0,0 +1,229 @@
+// NTP timestamps count UTC seconds since 1900-01-01 00:00:00 in a 32-bit field, followed by a
+// 32-bit binary fraction of a second. Like Unix time, NTP time skips leap seconds: every day is
+// 86,400 seconds long. So converting between NTP time and an Instant means adding or removing the
+// leap seconds that have been inserted into UTC, which we get from the chronology.
+//
+// The seconds field wraps around every 2^32 seconds (about 136 years). RFC 5905 calls each such
+// period an era; era 0 started in 1900 and era 1 starts on 2036-02-07 06:28:16 UTC. The era is
+// not transmitted, so it has to be supplied or inferred from a reference time.
+
+use num_traits::ToPrimitive;
+
+use crate::instant::Tick;
+use crate::iso8601::Chronology;
+use crate::rounding::Rounding;
+use crate::scale::{ScaleConversion, Seconds};
+use crate::{Instant, Scale};
+
+// Number of seconds from 1900-01-01 to 1970-01-01, i.e. 70 years of which 17 were leap years.
+const NTP_UNIX_OFFSET_SECONDS: i64 = 2_208_988_800;
+const SECONDS_PER_ERA: i64 = 1 << 32;
+
+// The 32.32 fixed-point value of a timestamp, i.e. the time in units of 2^-32 seconds, as a scale
+// so that instants of any scale can be converted to and from it.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct FixedPoint;
+
+impl Scale for FixedPoint {
+    const TICKS_PER_SECOND: u64 = 1 << 32;
+}
+
+/// An NTP timestamp in the 64-bit 32.32 fixed-point format of RFC 5905.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct NtpTimestamp {
+    seconds: u32,
+    fraction: u32,
+}
+
+impl NtpTimestamp {
+    pub fn new(seconds: u32, fraction: u32) -> Self {
+        NtpTimestamp { seconds, fraction }
+    }
+
+    /// Creates a timestamp from its 64-bit wire representation, with the seconds in the upper
+    /// 32 bits.
+    pub fn from_bits(bits: u64) -> Self {
+        NtpTimestamp {
+            seconds: (bits >> 32) as u32,
+            fraction: bits as u32,
+        }
+    }
+
+    pub fn to_bits(&self) -> u64 {
+        (self.seconds as u64) << 32 | self.fraction as u64
+    }
+
+    /// Seconds since the start of the era.
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Fraction of a second, in units of 2^-32 seconds.
+    pub fn fraction(&self) -> u32 {
+        self.fraction
+    }
+
+    /// Returns the era and NTP timestamp of the given instant, with leap seconds taken from the
+    /// given chronology. Returns None if the instant can't be represented in the seconds range of
+    /// an i64.
+    ///
+    /// The fraction is rounded up, which guarantees that converting the timestamp back with
+    /// [NtpTimestamp::to_instant] gives the original instant for any scale with at most 2^32
+    /// ticks per second. During a leap second, NTP time repeats the first second of the next
+    /// day, so a leap second can't be told apart from the second that follows it.
+    pub fn from_instant<T: Tick, S: Scale>(
+        instant: Instant<T, S>,
+        chronology: &Chronology,
+    ) -> Option<(i32, NtpTimestamp)> {
+        let fixed_point = ScaleConversion::<S, FixedPoint>::convert(
+            instant.ticks_since_epoch().to_i128()?,
+            Rounding::Ceil,
+        )?;
+        let seconds =
+            Instant::<i64, Seconds>::from_ticks_since_epoch((fixed_point >> 32).to_i64()?);
+        let leap_seconds = chronology
+            .leap_seconds()
+            .accumulated_leap_seconds_by_instant(seconds);
+        let unix_seconds = seconds
+            .ticks_since_epoch()
+            .checked_sub(leap_seconds as i64)?;
+        let ntp_seconds = unix_seconds.checked_add(NTP_UNIX_OFFSET_SECONDS)?;
+        let era = ntp_seconds.div_euclid(SECONDS_PER_ERA).to_i32()?;
+        let seconds = ntp_seconds.rem_euclid(SECONDS_PER_ERA) as u32;
+
+        // The low 32 bits are the fraction, also for negative values.
+        let fraction = fixed_point as u32;
+        Some((era, NtpTimestamp::new(seconds, fraction)))
+    }
+
+    /// Returns the instant of the timestamp in the given era, with leap seconds taken from the
+    /// given chronology. The fraction is truncated to the scale of the instant. Returns None if
+    /// the instant can't be represented in T.
+    pub fn to_instant<T: Tick, S: Scale>(
+        &self,
+        era: i32,
+        chronology: &Chronology,
+    ) -> Option<Instant<T, S>> {
+        let ntp_seconds = era as i64 * SECONDS_PER_ERA + self.seconds as i64;
+        let unix_seconds = ntp_seconds - NTP_UNIX_OFFSET_SECONDS;
+        let day = unix_seconds.div_euclid(86_400).to_i32()?;
+        let leap_seconds = chronology
+            .leap_seconds()
+            .accumulated_leap_seconds_by_day(day);
+        let seconds = unix_seconds + leap_seconds as i64;
+
+        let fixed_point = (seconds as i128) << 32 | self.fraction as i128;
+        let ticks = ScaleConversion::<FixedPoint, S>::convert(fixed_point, Rounding::Floor)?;
+        Some(Instant::from_ticks_since_epoch(T::from(ticks)?))
+    }
+
+    /// Returns the instant of the timestamp in whichever era puts it closest to the reference
+    /// instant. This is the usual way to resolve the era, and gives the right result as long as
+    /// the reference is within 68 years of the actual time.
+    pub fn to_instant_near<T: Tick, S: Scale>(
+        &self,
+        reference: Instant<T, S>,
+        chronology: &Chronology,
+    ) -> Option<Instant<T, S>> {
+        let (reference_era, reference_timestamp) = Self::from_instant(reference, chronology)?;
+        // Wrapping subtraction gives the signed distance from the reference modulo 2^32.
+        let offset = self.seconds.wrapping_sub(reference_timestamp.seconds) as i32 as i64;
+        let ntp_seconds =
+            reference_era as i64 * SECONDS_PER_ERA + reference_timestamp.seconds as i64 + offset;
+        let era = ntp_seconds.div_euclid(SECONDS_PER_ERA).to_i32()?;
+        self.to_instant(era, chronology)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instant::InstantS64;
+    use crate::iso8601::{load_chronology, DateTime};
+    use crate::scale::Timebase;
+    use crate::InstantNs128;
+
+    #[test]
+    fn unix_epoch() {
+        let chronology = load_chronology("UTC");
+        let (era, timestamp) =
+            NtpTimestamp::from_instant(InstantS64::from_ticks_since_epoch(0), &chronology).unwrap();
+        assert_eq!(era, 0);
+        assert_eq!(timestamp, NtpTimestamp::new(2_208_988_800, 0));
+    }
+
+    #[test]
+    fn leap_seconds_are_removed() {
+        let chronology = load_chronology("UTC");
+        // 2017-01-01 00:00:00 UTC, after the 27th leap second.
+        let date_time = DateTime::builder()
+            .chronology(&chronology)
+            .year(2017)
+            .month(1)
+            .day(1)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .build();
+        let instant: InstantS64 = date_time.to_instant().unwrap();
+        assert_eq!(instant.ticks_since_epoch(), 1_483_228_800 + 27);
+        let (era, timestamp) = NtpTimestamp::from_instant(instant, &chronology).unwrap();
+        assert_eq!(era, 0);
+        assert_eq!(timestamp.seconds(), 3_692_217_600);
+        assert_eq!(timestamp.to_instant(era, &chronology), Some(instant));
+
+        // The leap second before it has the same NTP time.
+        let leap_second = InstantS64::from_ticks_since_epoch(instant.ticks_since_epoch() - 1);
+        let (_, leap_timestamp) = NtpTimestamp::from_instant(leap_second, &chronology).unwrap();
+        assert_eq!(leap_timestamp, timestamp);
+    }
+
+    #[test]
+    fn round_trip() {
+        let chronology = load_chronology("UTC");
+        for ticks in [
+            -1_000_000_000_000_000_001,
+            0,
+            1_700_000_000_123_456_789,
+            2_085_978_496_999_999_999,
+            5_000_000_000_000_000_000,
+        ] {
+            let instant = InstantNs128::from_ticks_since_epoch(ticks);
+            let (era, timestamp) = NtpTimestamp::from_instant(instant, &chronology).unwrap();
+            assert_eq!(timestamp.to_instant(era, &chronology), Some(instant));
+            let bits = timestamp.to_bits();
+            assert_eq!(NtpTimestamp::from_bits(bits), timestamp);
+        }
+    }
+
+    #[test]
+    fn round_trip_timebase() {
+        let chronology = load_chronology("UTC");
+        for ticks in [-1, 0, 1, 50_955_044_955] {
+            let instant = Instant::<i64, Timebase<30_000, 1_001>>::from_ticks_since_epoch(ticks);
+            let (era, timestamp) = NtpTimestamp::from_instant(instant, &chronology).unwrap();
+            assert_eq!(timestamp.to_instant(era, &chronology), Some(instant));
+        }
+    }
+
+    #[test]
+    fn era_rollover() {
+        let chronology = load_chronology("UTC");
+        // 2036-02-07 06:28:16 UTC is the start of era 1.
+        let era_1 = InstantS64::from_ticks_since_epoch(2_085_978_496 + 27);
+        let (era, timestamp) = NtpTimestamp::from_instant(era_1, &chronology).unwrap();
+        assert_eq!(era, 1);
+        assert_eq!(timestamp, NtpTimestamp::new(0, 0));
+
+        // A reference shortly before the rollover resolves to era 1.
+        let reference = InstantS64::from_ticks_since_epoch(era_1.ticks_since_epoch() - 3600);
+        assert_eq!(
+            timestamp.to_instant_near(reference, &chronology),
+            Some(era_1)
+        );
+        // And a timestamp shortly before the rollover resolves to era 0 with a reference after it.
+        let before = NtpTimestamp::new(u32::MAX, 0);
+        let expected = InstantS64::from_ticks_since_epoch(era_1.ticks_since_epoch() - 1);
+        assert_eq!(before.to_instant_near(era_1, &chronology), Some(expected));
+    }
+}
@@ -0,0 +1,237 @@
+// Compact representations of an instant for storing many of them, e.g. in a columnar store.
+// Instant<i128, Nanoseconds> takes 16 bytes with 16-byte alignment on most platforms; these take
+// 12 bytes with 4-byte alignment, or 8 bytes for a limited range. Both are converted to an
+// Instant for anything other than storage and comparison.
+
+use std::cmp::Ordering;
+
+use crate::instant::Tick;
+use crate::{Instant, InstantNs128, InstantOutOfRange, Nanoseconds, Scale};
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+/// An instant stored as 64-bit seconds and 32-bit nanoseconds since the epoch, in 12 bytes with
+/// 4-byte alignment. This covers about 292 billion years on either side of the epoch, with
+/// nanosecond precision.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[repr(C, packed(4))]
+pub struct PackedInstant {
+    // Ordered so that the derived comparisons are correct, since the nanoseconds are always less
+    // than a second.
+    seconds: i64,
+    nanoseconds: u32,
+}
+
+impl PackedInstant {
+    /// Creates an instant from whole seconds since the epoch and the nanoseconds since the start
+    /// of that second. Returns None if the nanoseconds are a second or more.
+    pub fn new(seconds: i64, nanoseconds: u32) -> Option<Self> {
+        if nanoseconds >= NANOSECONDS_PER_SECOND {
+            return None;
+        }
+        Some(PackedInstant {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Seconds since the epoch, rounded towards negative infinity.
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Nanoseconds since the start of the second.
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Packs the given instant. Ticks finer than a nanosecond are rounded down.
+    pub fn from_instant<T: Tick, S: Scale>(
+        instant: Instant<T, S>,
+    ) -> Result<Self, InstantOutOfRange> {
+        let (seconds, nanoseconds) = instant
+            .to_seconds_and_nanoseconds()
+            .ok_or(InstantOutOfRange)?;
+        Ok(PackedInstant {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Unpacks the instant into the scale S, rounding down if S is coarser than nanoseconds.
+    pub fn to_instant<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, InstantOutOfRange> {
+        Instant::from_seconds_and_nanoseconds(self.seconds, self.nanoseconds)
+            .ok_or(InstantOutOfRange)
+    }
+}
+
+impl From<PackedInstant> for InstantNs128 {
+    fn from(value: PackedInstant) -> Self {
+        // The seconds of an i64 always fit in an i128 of nanoseconds.
+        value.to_instant().unwrap()
+    }
+}
+
+/// An instant stored as 64-bit nanoseconds since the epoch, in 8 bytes. This covers about 292
+/// years on either side of the epoch, from 1677-09-21 to 2262-04-11, and conversions check that
+/// the instant is within that range.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PackedInstantNs64(i64);
+
+impl PackedInstantNs64 {
+    pub const MIN: PackedInstantNs64 = PackedInstantNs64(i64::MIN);
+    pub const MAX: PackedInstantNs64 = PackedInstantNs64(i64::MAX);
+
+    pub fn from_nanoseconds_since_epoch(nanoseconds: i64) -> Self {
+        PackedInstantNs64(nanoseconds)
+    }
+
+    pub fn nanoseconds_since_epoch(&self) -> i64 {
+        self.0
+    }
+
+    /// Packs the given instant. Ticks finer than a nanosecond are rounded down. Returns an error
+    /// if the instant is outside the range of this type.
+    pub fn from_instant<T: Tick, S: Scale>(
+        instant: Instant<T, S>,
+    ) -> Result<Self, InstantOutOfRange> {
+        let instant: Instant<i64, Nanoseconds> =
+            PackedInstant::from_instant(instant)?.to_instant()?;
+        Ok(PackedInstantNs64(instant.ticks_since_epoch()))
+    }
+
+    /// Unpacks the instant into the scale S, rounding down if S is coarser than nanoseconds.
+    pub fn to_instant<T: Tick, S: Scale>(&self) -> Result<Instant<T, S>, InstantOutOfRange> {
+        PackedInstant::from_instant(Instant::<i64, Nanoseconds>::from(*self))?.to_instant()
+    }
+}
+
+impl From<Instant<i64, Nanoseconds>> for PackedInstantNs64 {
+    fn from(value: Instant<i64, Nanoseconds>) -> Self {
+        PackedInstantNs64(value.ticks_since_epoch())
+    }
+}
+
+impl From<PackedInstantNs64> for Instant<i64, Nanoseconds> {
+    fn from(value: PackedInstantNs64) -> Self {
+        Instant::from_ticks_since_epoch(value.0)
+    }
+}
+
+impl From<PackedInstantNs64> for PackedInstant {
+    fn from(value: PackedInstantNs64) -> Self {
+        PackedInstant::from_instant(Instant::<i64, Nanoseconds>::from(value)).unwrap()
+    }
+}
+
+// Comparisons with instants go through the cross-type comparisons of Instant.
+macro_rules! impl_instant_comparisons {
+    ($Packed:ty, $Unpacked:ty) => {
+        impl<T: Tick, S: Scale> PartialEq<Instant<T, S>> for $Packed {
+            fn eq(&self, other: &Instant<T, S>) -> bool {
+                <$Unpacked>::from(*self) == *other
+            }
+        }
+
+        impl<T: Tick, S: Scale> PartialOrd<Instant<T, S>> for $Packed {
+            fn partial_cmp(&self, other: &Instant<T, S>) -> Option<Ordering> {
+                <$Unpacked>::from(*self).partial_cmp(other)
+            }
+        }
+
+        impl<T: Tick, S: Scale> PartialEq<$Packed> for Instant<T, S> {
+            fn eq(&self, other: &$Packed) -> bool {
+                *self == <$Unpacked>::from(*other)
+            }
+        }
+
+        impl<T: Tick, S: Scale> PartialOrd<$Packed> for Instant<T, S> {
+            fn partial_cmp(&self, other: &$Packed) -> Option<Ordering> {
+                self.partial_cmp(&<$Unpacked>::from(*other))
+            }
+        }
+    };
+}
+
+impl_instant_comparisons!(PackedInstant, InstantNs128);
+impl_instant_comparisons!(PackedInstantNs64, Instant<i64, Nanoseconds>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instant::InstantS64;
+    use crate::scale::{Attoseconds, Milliseconds, Seconds};
+    use crate::Duration;
+
+    #[test]
+    fn layout() {
+        assert_eq!(std::mem::size_of::<PackedInstant>(), 12);
+        assert_eq!(std::mem::align_of::<PackedInstant>(), 4);
+        assert_eq!(std::mem::size_of::<Option<PackedInstant>>(), 16);
+        assert_eq!(std::mem::size_of::<PackedInstantNs64>(), 8);
+    }
+
+    #[test]
+    fn packed_instant() {
+        let instant =
+            Instant::<i128, Attoseconds>::from_ticks_since_epoch(-1_500_000_000_000_000_001);
+        let packed = PackedInstant::from_instant(instant).unwrap();
+        assert_eq!((packed.seconds(), packed.nanoseconds()), (-2, 499_999_999));
+        assert_eq!(packed, PackedInstant::new(-2, 499_999_999).unwrap());
+        assert_eq!(PackedInstant::new(0, 1_000_000_000), None);
+        assert_eq!(
+            packed.to_instant::<i64, Milliseconds>().unwrap(),
+            Instant::<i64, Milliseconds>::from_ticks_since_epoch(-1_501)
+        );
+        assert_eq!(
+            InstantNs128::from(packed),
+            InstantNs128::from_ticks_since_epoch(-1_500_000_001)
+        );
+        assert!(packed.to_instant::<i32, Attoseconds>().is_err());
+        assert!(
+            PackedInstant::from_instant(Instant::<i128, Seconds>::from_ticks_since_epoch(
+                i128::MAX
+            ))
+            .is_err()
+        );
+
+        // Rounded down, so the packed instant is earlier.
+        assert!(packed < instant);
+        assert!(instant > packed);
+        assert_eq!(
+            packed,
+            InstantS64::from_ticks_since_epoch(-2) + Duration::<i64, Nanoseconds>::new(499_999_999)
+        );
+        assert!(PackedInstant::new(-1, 0).unwrap() > packed);
+        assert!(PackedInstant::new(-2, 500_000_000).unwrap() > packed);
+    }
+
+    #[test]
+    fn packed_instant_ns64() {
+        let instant = InstantS64::from_ticks_since_epoch(9_223_372_036);
+        let packed = PackedInstantNs64::from_instant(instant).unwrap();
+        assert_eq!(packed.nanoseconds_since_epoch(), 9_223_372_036_000_000_000);
+        assert_eq!(packed.to_instant::<i64, Seconds>().unwrap(), instant);
+        assert_eq!(packed, instant);
+        assert!(
+            PackedInstantNs64::from_instant(instant + Duration::<i64, Seconds>::new(1)).is_err()
+        );
+        assert!(
+            PackedInstantNs64::from_instant(InstantS64::from_ticks_since_epoch(-9_223_372_037))
+                .is_err()
+        );
+        assert_eq!(
+            PackedInstantNs64::MIN.to_instant::<i64, Seconds>().unwrap(),
+            InstantS64::from_ticks_since_epoch(-9_223_372_037)
+        );
+        assert!(PackedInstantNs64::MAX.to_instant::<i32, Seconds>().is_err());
+
+        let packed = PackedInstantNs64::from_nanoseconds_since_epoch(-1);
+        assert_eq!(
+            PackedInstant::from(packed),
+            PackedInstant::new(-1, 999_999_999).unwrap()
+        );
+        assert!(packed < InstantS64::from_ticks_since_epoch(0));
+        assert!(InstantS64::from_ticks_since_epoch(-1) < packed);
+    }
+}
@@ -0,0 +1,112 @@
+// PTP (IEEE 1588) timestamps count TAI seconds since the PTP epoch, 1970-01-01 00:00:00 TAI, in a
+// 48-bit field, followed by a 32-bit nanoseconds field. TAI has no leap seconds, and neither does
+// the count of an Instant, so the two differ only by the constant TAI − UTC offset that was in
+// effect when leap seconds were introduced in 1972. No leap-second table is needed.
+//
+// Strictly speaking TAI − UTC was not a whole number of seconds before 1972, so the PTP epoch is
+// not exactly 10 seconds before the Instant epoch. Like other PTP implementations we ignore the
+// pre-1972 rubber seconds and use the same offset throughout.
+
+use crate::instant::Tick;
+use crate::zoneinfo::TAI_MINUS_UTC_AT_1972;
+use crate::{Instant, Scale};
+
+const MAX_SECONDS: u64 = (1 << 48) - 1;
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+/// A PTP timestamp, as carried in the originTimestamp and similar fields of PTP messages.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct PtpTimestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl PtpTimestamp {
+    /// Creates a timestamp. Returns None if the seconds don't fit in 48 bits or the
+    /// nanoseconds are not less than one second.
+    pub fn new(seconds: u64, nanoseconds: u32) -> Option<Self> {
+        if seconds > MAX_SECONDS || nanoseconds >= NANOSECONDS_PER_SECOND {
+            return None;
+        }
+        Some(PtpTimestamp {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Creates a timestamp from its 10-byte big-endian wire representation.
+    pub fn from_bytes(bytes: [u8; 10]) -> Option<Self> {
+        let mut seconds = [0u8; 8];
+        seconds[2..].copy_from_slice(&bytes[..6]);
+        let nanoseconds = [bytes[6], bytes[7], bytes[8], bytes[9]];
+        Self::new(u64::from_be_bytes(seconds), u32::from_be_bytes(nanoseconds))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        bytes[..6].copy_from_slice(&self.seconds.to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&self.nanoseconds.to_be_bytes());
+        bytes
+    }
+
+    /// TAI seconds since the PTP epoch.
+    pub fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Returns the PTP timestamp of the given instant. Subsecond ticks that are finer than a
+    /// nanosecond are rounded down. Returns None if the instant is before the PTP epoch or after
+    /// the end of the 48-bit range.
+    pub fn from_instant<T: Tick, S: Scale>(instant: Instant<T, S>) -> Option<Self> {
+        let (seconds, nanoseconds) = instant.to_seconds_and_nanoseconds()?;
+        let seconds = seconds.checked_add(TAI_MINUS_UTC_AT_1972 as i64)?;
+        Self::new(seconds.try_into().ok()?, nanoseconds)
+    }
+
+    /// Returns the instant of the timestamp. Nanoseconds that can't be represented in the scale
+    /// of the instant are rounded down. Returns None if the instant can't be represented in T.
+    pub fn to_instant<T: Tick, S: Scale>(&self) -> Option<Instant<T, S>> {
+        let seconds = self.seconds as i64 - TAI_MINUS_UTC_AT_1972 as i64;
+        Instant::from_seconds_and_nanoseconds(seconds, self.nanoseconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instant::InstantS64;
+    use crate::InstantNs128;
+
+    #[test]
+    fn epoch() {
+        let instant = InstantS64::from_ticks_since_epoch(-10);
+        let timestamp = PtpTimestamp::from_instant(instant).unwrap();
+        assert_eq!(timestamp, PtpTimestamp::new(0, 0).unwrap());
+        assert!(PtpTimestamp::from_instant(InstantS64::from_ticks_since_epoch(-11)).is_none());
+    }
+
+    #[test]
+    fn tai_offset() {
+        // 2017-01-01 00:00:00 UTC is 1483228800 in Unix time. TAI − UTC was 37 seconds at that
+        // point, 27 of which are leap seconds counted by the Instant.
+        let instant = InstantS64::from_ticks_since_epoch(1_483_228_800 + 27);
+        let timestamp = PtpTimestamp::from_instant(instant).unwrap();
+        assert_eq!(timestamp.seconds(), 1_483_228_800 + 37);
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant = InstantNs128::from_ticks_since_epoch(1_700_000_000_123_456_789);
+        let timestamp = PtpTimestamp::from_instant(instant).unwrap();
+        assert_eq!(timestamp.nanoseconds(), 123_456_789);
+        assert_eq!(timestamp.to_instant(), Some(instant));
+
+        let bytes = timestamp.to_bytes();
+        assert_eq!(PtpTimestamp::from_bytes(bytes), Some(timestamp));
+        assert!(PtpTimestamp::from_bytes([0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0x00]).is_none());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::instant::Tick;
+use crate::wide::{div_rem_wide, mul_wide};
+
+/// How to round when converting to a coarser scale or to a multiple of a duration.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero, i.e. truncate.
+    TowardZero,
+    /// Round to the nearest value, and away from zero if there are two nearest values.
+    HalfUp,
+    /// Round to the nearest value, and to the even one if there are two nearest values. This
+    /// avoids the bias of [Rounding::HalfUp] when rounding many values.
+    HalfEven,
+}
+
+/// Computes value * multiplier / divisor, rounding according to the mode. Returns None if the
+/// result doesn't fit in T. The divisor must not be zero. Intermediate results are computed with
+/// enough precision that this never overflows unless the result does.
+pub(crate) fn mul_div_round<T: Tick>(
+    value: T,
+    multiplier: u128,
+    divisor: u128,
+    mode: Rounding,
+) -> Option<T> {
+    let (negative, quotient, remainder) = mul_div_magnitude(value, multiplier, divisor)?;
+    if remainder == 0 {
+        return from_magnitude(negative, quotient);
+    }
+
+    // The quotient was truncated towards zero, i.e. its magnitude rounded down. Work out whether
+    // to round the magnitude up instead.
+    let round_up = match mode {
+        Rounding::TowardZero => false,
+        Rounding::Floor => negative,
+        Rounding::Ceil => !negative,
+        Rounding::HalfUp | Rounding::HalfEven => {
+            // Compare the remainder with the distance to the next multiple.
+            match remainder.cmp(&(divisor - remainder)) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal => mode == Rounding::HalfUp || quotient % 2 == 1,
+            }
+        }
+    };
+    let quotient = if round_up {
+        quotient.checked_add(1)?
+    } else {
+        quotient
+    };
+    from_magnitude(negative, quotient)
+}
+
+/// Computes value * multiplier / divisor if the result is exact. Returns None if there's a
+/// remainder or the result doesn't fit in T. The divisor must not be zero.
+pub(crate) fn mul_div_exact<T: Tick>(value: T, multiplier: u128, divisor: u128) -> Option<T> {
+    match mul_div_magnitude(value, multiplier, divisor)? {
+        (negative, quotient, 0) => from_magnitude(negative, quotient),
+        _ => None,
+    }
+}
+
+/// Divides value by a positive divisor, rounding according to the mode. The result always fits
+/// in T since the divisor is at least one.
+pub(crate) fn div_round_by<T: Tick>(value: T, divisor: T, mode: Rounding) -> T {
+    debug_assert!(divisor > T::zero());
+    let divisor = divisor.to_u128().unwrap();
+    mul_div_round(value, 1, divisor, mode).unwrap()
+}
+
+// Returns the sign, and the magnitudes of the quotient (truncated) and remainder of
+// value * multiplier / divisor. Returns None if the quotient doesn't fit in a u128.
+fn mul_div_magnitude<T: Tick>(
+    value: T,
+    multiplier: u128,
+    divisor: u128,
+) -> Option<(bool, u128, u128)> {
+    debug_assert!(divisor != 0);
+    let negative = value < T::zero();
+    let magnitude = if negative {
+        value.to_i128().unwrap().unsigned_abs()
+    } else {
+        value.to_u128().unwrap()
+    };
+    let (high, low) = mul_wide(magnitude, multiplier);
+    let (quotient, remainder) = div_rem_wide(high, low, divisor)?;
+    Some((negative, quotient, remainder))
+}
+
+fn from_magnitude<T: Tick>(negative: bool, magnitude: u128) -> Option<T> {
+    if !negative {
+        T::from(magnitude)
+    } else if magnitude <= i128::MAX as u128 {
+        T::from(-(magnitude as i128))
+    } else if magnitude == i128::MIN.unsigned_abs() {
+        T::from(i128::MIN)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_modes() {
+        let cases: [(i32, [i32; 5]); 7] = [
+            // value, [floor, ceil, toward zero, half up, half even] of value / 10
+            (25, [2, 3, 2, 3, 2]),
+            (35, [3, 4, 3, 4, 4]),
+            (-25, [-3, -2, -2, -3, -2]),
+            (-35, [-4, -3, -3, -4, -4]),
+            (24, [2, 3, 2, 2, 2]),
+            (-26, [-3, -2, -2, -3, -3]),
+            (30, [3, 3, 3, 3, 3]),
+        ];
+        let modes = [
+            Rounding::Floor,
+            Rounding::Ceil,
+            Rounding::TowardZero,
+            Rounding::HalfUp,
+            Rounding::HalfEven,
+        ];
+        for (value, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(
+                    div_round_by(value, 10, mode),
+                    expected,
+                    "{} {:?}",
+                    value,
+                    mode
+                );
+                assert_eq!(mul_div_round(value, 1, 10, mode), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn divisor_wider_than_value() {
+        assert_eq!(mul_div_round(i8::MIN, 1, 1_000, Rounding::Floor), Some(-1));
+        assert_eq!(mul_div_round(i8::MAX, 1, 1_000, Rounding::Ceil), Some(1));
+        assert_eq!(mul_div_round(i8::MAX, 1, 1_000, Rounding::HalfUp), Some(0));
+        assert_eq!(mul_div_round(200u8, 1, 300, Rounding::HalfEven), Some(1));
+    }
+
+    #[test]
+    fn wide_intermediates() {
+        // i128::MIN * 1000 / 1000 overflows an i128 in the intermediate product.
+        assert_eq!(
+            mul_div_round(i128::MIN, 1_000, 1_000, Rounding::Floor),
+            Some(i128::MIN)
+        );
+        assert_eq!(mul_div_exact(i128::MAX, 3, 3), Some(i128::MAX));
+        assert_eq!(mul_div_exact(i128::MAX, 3, 2), None);
+        assert_eq!(mul_div_exact(10i32, 9, 100_000), None);
+        assert_eq!(mul_div_exact(-200_000i32, 9, 100_000), Some(-18));
+        assert_eq!(mul_div_round(-1i8, 1, 2, Rounding::HalfEven), Some(0));
+        assert_eq!(
+            mul_div_round(u128::MAX, 1, 1, Rounding::Ceil),
+            Some(u128::MAX)
+        );
+        assert_eq!(mul_div_round(u64::MAX, 2, 1, Rounding::Ceil), None::<u64>);
+    }
+}
@@ -1,26 +1,329 @@
+use std::cmp::Ordering;
 use std::hash::Hash;
+use std::marker::PhantomData;
 
+use num_rational::Ratio;
+
+use crate::instant::Tick;
+use crate::rounding::{mul_div_exact, mul_div_round, Rounding};
+use crate::wide::mul_wide;
+
+/// The unit of the ticks of an [Instant](crate::Instant) or [Duration](crate::Duration).
+///
+/// Any whole number of ticks per second is allowed, so besides the decimal scales provided here
+/// you can define scales such as 1/65,536 s or the 90 kHz clock of MPEG transport streams. The
+/// number of ticks per second may also be a fraction, TICKS_PER_SECOND /
+/// TICKS_PER_SECOND_DENOMINATOR, as in the timebases of video and audio; see [Timebase].
+/// Conversions between scales whose ticks don't divide each other are rounded.
 pub trait Scale: Clone + Copy + Ord + PartialOrd + Eq + PartialEq + Hash + Sized {
-    const TICKS_PER_SECOND: u32;
+    const TICKS_PER_SECOND: u64;
+    const TICKS_PER_SECOND_DENOMINATOR: u64 = 1;
+
+    /// The number of ticks per second, as a fraction in lowest terms.
+    fn ticks_per_second() -> Ratio<u64> {
+        Ratio::new(Self::TICKS_PER_SECOND, Self::TICKS_PER_SECOND_DENOMINATOR)
+    }
 }
 
-#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct Seconds;
+macro_rules! scale {
+    ($Name:ident, $ticks_per_second:expr) => {
+        #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+        pub struct $Name;
 
-#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct Milliseconds;
+        impl Scale for $Name {
+            const TICKS_PER_SECOND: u64 = $ticks_per_second;
+        }
+    };
+}
+
+scale!(Seconds, 1);
+scale!(Deciseconds, 10);
+scale!(Milliseconds, 1_000);
+scale!(Microseconds, 1_000_000);
+scale!(Nanoseconds, 1_000_000_000);
+scale!(Picoseconds, 1_000_000_000_000);
+scale!(Attoseconds, 1_000_000_000_000_000_000);
 
+/// A scale of N / D ticks per second, i.e. each tick lasts D / N seconds. For example, NTSC video
+/// runs at 30,000 / 1,001 frames per second, so frames are counted by `Timebase<30_000, 1_001>`,
+/// and audio samples at 48 kHz by `Timebase<48_000, 1>`.
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
-pub struct Nanoseconds;
+pub struct Timebase<const N: u64, const D: u64>;
+
+impl<const N: u64, const D: u64> Scale for Timebase<N, D> {
+    const TICKS_PER_SECOND: u64 = N;
+    const TICKS_PER_SECOND_DENOMINATOR: u64 = D;
+}
+
+/// The finer of two scales, i.e. the one with more ticks per second. Used as the scale of the
+/// result when adding or subtracting instants and durations of different scales. A scale is
+/// always compatible with itself; combinations of different scales need an implementation,
+/// which we provide for the built-in scales.
+pub trait FinerScale<S: Scale>: Scale {
+    type Output: Scale;
+}
 
-impl Scale for Seconds {
-    const TICKS_PER_SECOND: u32 = 1;
+impl<S: Scale> FinerScale<S> for S {
+    type Output = S;
 }
 
-impl Scale for Milliseconds {
-    const TICKS_PER_SECOND: u32 = 1_000;
+pub(crate) type CommonScale<S1, S2> = <S1 as FinerScale<S2>>::Output;
+
+macro_rules! impl_finer_scale {
+    ($Coarser:ty, $Finer:ty) => {
+        impl FinerScale<$Finer> for $Coarser {
+            type Output = $Finer;
+        }
+        impl FinerScale<$Coarser> for $Finer {
+            type Output = $Finer;
+        }
+    };
 }
 
-impl Scale for Nanoseconds {
-    const TICKS_PER_SECOND: u32 = 1_000_000_000;
+impl_finer_scale!(Seconds, Deciseconds);
+impl_finer_scale!(Seconds, Milliseconds);
+impl_finer_scale!(Seconds, Microseconds);
+impl_finer_scale!(Seconds, Nanoseconds);
+impl_finer_scale!(Seconds, Picoseconds);
+impl_finer_scale!(Seconds, Attoseconds);
+impl_finer_scale!(Deciseconds, Milliseconds);
+impl_finer_scale!(Deciseconds, Microseconds);
+impl_finer_scale!(Deciseconds, Nanoseconds);
+impl_finer_scale!(Deciseconds, Picoseconds);
+impl_finer_scale!(Deciseconds, Attoseconds);
+impl_finer_scale!(Milliseconds, Microseconds);
+impl_finer_scale!(Milliseconds, Nanoseconds);
+impl_finer_scale!(Milliseconds, Picoseconds);
+impl_finer_scale!(Milliseconds, Attoseconds);
+impl_finer_scale!(Microseconds, Nanoseconds);
+impl_finer_scale!(Microseconds, Picoseconds);
+impl_finer_scale!(Microseconds, Attoseconds);
+impl_finer_scale!(Nanoseconds, Picoseconds);
+impl_finer_scale!(Nanoseconds, Attoseconds);
+impl_finer_scale!(Picoseconds, Attoseconds);
+
+// The conversion of ticks of From to ticks of To, as the reduced fraction MULTIPLIER / DIVISOR.
+// The COARSER, FINER and WHOLE constants are compile-time checks on the direction of the
+// conversion: evaluating one of them in a generic function makes it fail to compile when
+// instantiated with scales that don't pass.
+pub(crate) struct ScaleConversion<From, To>(PhantomData<(From, To)>);
+
+impl<From: Scale, To: Scale> ScaleConversion<From, To> {
+    const CONVERSION: Conversion = Conversion::new(
+        From::TICKS_PER_SECOND,
+        From::TICKS_PER_SECOND_DENOMINATOR,
+        To::TICKS_PER_SECOND,
+        To::TICKS_PER_SECOND_DENOMINATOR,
+    );
+    pub(crate) const MULTIPLIER: u128 = Self::CONVERSION.multiplier;
+    pub(crate) const DIVISOR: u128 = Self::CONVERSION.divisor;
+
+    /// To is coarser than (or the same as) From.
+    pub(crate) const COARSER: () = assert!(
+        Self::MULTIPLIER <= Self::DIVISOR,
+        "cannot convert to a finer scale"
+    );
+
+    /// To is finer than (or the same as) From.
+    pub(crate) const FINER: () = assert!(
+        Self::MULTIPLIER >= Self::DIVISOR,
+        "cannot convert to a coarser scale"
+    );
+
+    /// To is coarser than (or the same as) From, and each tick of To is a whole number of ticks
+    /// of From.
+    pub(crate) const WHOLE: () = assert!(
+        Self::MULTIPLIER == 1,
+        "cannot split into a finer scale, or one whose ticks aren't whole ticks of this one"
+    );
+
+    /// Converts ticks of From to ticks of To, rounding according to the mode. Returns None if the
+    /// result doesn't fit in T.
+    pub(crate) fn convert<T: Tick>(ticks: T, mode: Rounding) -> Option<T> {
+        Self::CONVERSION.convert(ticks, mode)
+    }
+
+    /// Converts ticks of From to ticks of To if that can be done exactly. Returns None if there
+    /// would be a remainder or the result doesn't fit in T.
+    pub(crate) fn convert_exact<T: Tick>(ticks: T) -> Option<T> {
+        Self::CONVERSION.convert_exact(ticks)
+    }
+
+    /// Compares ticks of From with ticks of To exactly, whatever their types.
+    pub(crate) fn cmp<T1: Tick, T2: Tick>(from: T1, to: T2) -> Ordering {
+        Self::CONVERSION.cmp(from, to)
+    }
+}
+
+// The conversion between two numbers of ticks per second, each given as a numerator and a
+// denominator. This is what ScaleConversion computes at compile time; DynInstant and DynDuration
+// use it directly, since their scales are only known at runtime.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Conversion {
+    multiplier: u128,
+    divisor: u128,
+}
+
+impl Conversion {
+    pub(crate) const fn new(
+        from_numerator: u64,
+        from_denominator: u64,
+        to_numerator: u64,
+        to_denominator: u64,
+    ) -> Self {
+        // (to_numerator / to_denominator) / (from_numerator / from_denominator), with all four
+        // below 2^64, so the products fit in a u128.
+        let numerator = to_numerator as u128 * from_denominator as u128;
+        let denominator = from_numerator as u128 * to_denominator as u128;
+        let gcd = gcd(numerator, denominator);
+        Conversion {
+            multiplier: numerator / gcd,
+            divisor: denominator / gcd,
+        }
+    }
+
+    pub(crate) fn between(from: Ratio<u64>, to: Ratio<u64>) -> Self {
+        Self::new(*from.numer(), *from.denom(), *to.numer(), *to.denom())
+    }
+
+    pub(crate) fn convert<T: Tick>(&self, ticks: T, mode: Rounding) -> Option<T> {
+        mul_div_round(ticks, self.multiplier, self.divisor, mode)
+    }
+
+    pub(crate) fn convert_exact<T: Tick>(&self, ticks: T) -> Option<T> {
+        mul_div_exact(ticks, self.multiplier, self.divisor)
+    }
+
+    pub(crate) fn cmp<T1: Tick, T2: Tick>(&self, from: T1, to: T2) -> Ordering {
+        // from / from_ticks_per_second <=> to / to_ticks_per_second
+        // from * multiplier <=> to * divisor
+        let (from_negative, from) = sign_and_magnitude(from);
+        let (to_negative, to) = sign_and_magnitude(to);
+        let from = mul_wide(from, self.multiplier);
+        let to = mul_wide(to, self.divisor);
+        match (from_negative, to_negative) {
+            (false, false) => from.cmp(&to),
+            (true, true) => to.cmp(&from),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+        }
+    }
+}
+
+fn sign_and_magnitude<T: Tick>(ticks: T) -> (bool, u128) {
+    if ticks < T::zero() {
+        (true, ticks.to_i128().unwrap().unsigned_abs())
+    } else {
+        (false, ticks.to_u128().unwrap())
+    }
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The 90 kHz clock of MPEG transport streams.
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+    struct Mpeg;
+
+    impl Scale for Mpeg {
+        const TICKS_PER_SECOND: u64 = 90_000;
+    }
+
+    #[test]
+    fn conversion_factors() {
+        assert_eq!(
+            ScaleConversion::<Seconds, Attoseconds>::MULTIPLIER,
+            1_000_000_000_000_000_000
+        );
+        assert_eq!(ScaleConversion::<Seconds, Attoseconds>::DIVISOR, 1);
+        assert_eq!(ScaleConversion::<Nanoseconds, Mpeg>::MULTIPLIER, 9);
+        assert_eq!(ScaleConversion::<Nanoseconds, Mpeg>::DIVISOR, 100_000);
+    }
+
+    #[test]
+    fn rational_conversion() {
+        type Ntsc = Timebase<30_000, 1_001>;
+        assert_eq!(
+            ScaleConversion::<Ntsc, Nanoseconds>::MULTIPLIER,
+            100_100_000
+        );
+        assert_eq!(ScaleConversion::<Ntsc, Nanoseconds>::DIVISOR, 3);
+        assert_eq!(
+            ScaleConversion::<Timebase<48_000, 1>, Milliseconds>::DIVISOR,
+            48
+        );
+        assert_eq!(
+            ScaleConversion::<Ntsc, Seconds>::convert_exact(30_000i64),
+            Some(1_001)
+        );
+        assert_eq!(ScaleConversion::<Ntsc, Seconds>::convert_exact(1i64), None);
+        assert_eq!(
+            ScaleConversion::<Ntsc, Nanoseconds>::convert(3i64, Rounding::Floor),
+            Some(100_100_000)
+        );
+        assert_eq!(
+            ScaleConversion::<Ntsc, Nanoseconds>::convert(-1i64, Rounding::Floor),
+            Some(-33_366_667)
+        );
+        assert_eq!(
+            ScaleConversion::<Ntsc, Timebase<60_000, 1_001>>::convert_exact(7i32),
+            Some(14)
+        );
+        assert_eq!(
+            ScaleConversion::<Ntsc, Seconds>::cmp(30_000i64, 1_001i64),
+            Ordering::Equal
+        );
+        assert_eq!(Ntsc::ticks_per_second(), Ratio::new(30_000, 1_001));
+        assert_eq!(
+            Timebase::<50, 2>::ticks_per_second(),
+            Ratio::from_integer(25)
+        );
+    }
+
+    #[test]
+    fn non_divisible_conversion() {
+        // One MPEG tick is 11,111.1 ns.
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::convert(1i64, Rounding::Floor),
+            Some(11_111)
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::convert(1i64, Rounding::Ceil),
+            Some(11_112)
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::convert(9i64, Rounding::Floor),
+            Some(100_000)
+        );
+        assert_eq!(
+            ScaleConversion::<Nanoseconds, Mpeg>::convert(-100_001i64, Rounding::Floor),
+            Some(-10)
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::cmp(9i32, 100_000i64),
+            Ordering::Equal
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::cmp(1u8, 11_112u64),
+            Ordering::Less
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::cmp(-1i8, -11_112i64),
+            Ordering::Greater
+        );
+        assert_eq!(
+            ScaleConversion::<Mpeg, Nanoseconds>::cmp(0i8, 0u8),
+            Ordering::Equal
+        );
+    }
 }
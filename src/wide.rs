@@ -0,0 +1,75 @@
+// Unsigned 256-bit arithmetic, just enough to multiply 128-bit tick counts by 128-bit scale
+// factors and divide the result, or compare two such products, without overflowing. The value
+// is represented as a (high, low) pair of u128s.
+
+const HALF_BITS: u32 = 64;
+const LOW_MASK: u128 = (1 << HALF_BITS) - 1;
+
+/// Returns the full 256-bit product of a and b as (high, low).
+pub(crate) fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    if let Some(product) = a.checked_mul(b) {
+        return (0, product);
+    }
+
+    // Schoolbook multiplication with 64-bit limbs.
+    let (a_high, a_low) = (a >> HALF_BITS, a & LOW_MASK);
+    let (b_high, b_low) = (b >> HALF_BITS, b & LOW_MASK);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> HALF_BITS) + (low_high & LOW_MASK) + (high_low & LOW_MASK);
+    let low = (middle << HALF_BITS) | (low_low & LOW_MASK);
+    let high =
+        high_high + (low_high >> HALF_BITS) + (high_low >> HALF_BITS) + (middle >> HALF_BITS);
+    (high, low)
+}
+
+/// Divides the 256-bit value (high, low) by divisor, returning the quotient and remainder.
+/// Returns None if the quotient doesn't fit in a u128. The divisor must not be zero.
+pub(crate) fn div_rem_wide(high: u128, low: u128, divisor: u128) -> Option<(u128, u128)> {
+    debug_assert!(divisor != 0);
+    if high == 0 {
+        return Some((low / divisor, low % divisor));
+    }
+    if high >= divisor {
+        return None;
+    }
+
+    // Restoring division, one bit at a time. The remainder is always less than the divisor, but
+    // shifting it left can take it past 128 bits, which is tracked by the carry.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry != 0 || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_and_div() {
+        assert_eq!(mul_wide(3, 5), (0, 15));
+        assert_eq!(mul_wide(u128::MAX, 2), (1, u128::MAX - 1));
+        assert_eq!(mul_wide(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+
+        let (high, low) = mul_wide(u128::MAX, 1_000_000_007);
+        assert_eq!(div_rem_wide(high, low, 1_000_000_007), Some((u128::MAX, 0)));
+        let (high, low) = mul_wide(1 << 100, 1 << 100);
+        assert_eq!(
+            div_rem_wide(high, low, 3 << 80),
+            Some(((1 << 120) / 3, 1 << 80))
+        );
+        assert_eq!(div_rem_wide(high, low, 1 << 72), None);
+    }
+}
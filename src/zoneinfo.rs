@@ -1,13 +1,19 @@
 use std::cmp::Ordering::{Equal, Greater, Less};
 use std::path::PathBuf;
 
+use lazy_static::lazy_static;
 use numcmp::NumCmp;
 use zoneinfo_compiled::{parse, TZData};
 
 use crate::duration::DurationS32;
-use crate::instant::{InstantS32, Tick};
+use crate::instant::{InstantS32, InstantS64, Tick};
 use crate::scale::Seconds;
-use crate::{iso8601, Instant, Scale};
+use crate::{Instant, Scale};
+
+/// TAI − UTC in seconds at 1972-01-01 00:00:00 UTC, when UTC switched to whole leap seconds.
+/// Since then TAI − UTC has been this value plus the number of inserted leap seconds, so an
+/// Instant (which counts leap seconds since the Unix epoch) is a fixed offset from TAI.
+pub(crate) const TAI_MINUS_UTC_AT_1972: i32 = 10;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub(crate) struct LeapSecond {
@@ -35,6 +41,14 @@ pub(crate) struct LeapSecond {
 // TODO caching and, when caching, check the modification time of the file
 // in case it's been updated
 
+// The leap-second table is the same for all time zones, so we only need to load it once for
+// conversions that aren't tied to a particular chronology. These conversions report a missing
+// table as an error instead of panicking.
+lazy_static! {
+    static ref UTC_LEAP_SECONDS: Option<LeapSecondChronology> =
+        try_load_leap_segments().map(LeapSecondChronology);
+}
+
 fn tzdir() -> PathBuf {
     // Get the TZDIR environment variable. If it's not set, we default to /usr/share/zoneinfo.
     // We could try to be more clever here (look for the root directory that /etc/localtime points
@@ -182,9 +196,55 @@ impl LeapSecondChronology {
     pub fn by_day(&self, day: i32) -> SegmentLookupResult {
         lookup_leap_second_segment_by_day(&self.0, day)
     }
+
+    /// Returns the number of leap seconds that have been inserted into UTC before the start of
+    /// the given day (counted from the Unix epoch).
+    pub(crate) fn accumulated_leap_seconds_by_day(&self, day: i32) -> i32 {
+        match self.by_day(day) {
+            SegmentLookupResult::BeforeFirst(_) => 0,
+            SegmentLookupResult::In(segment) => segment.accumulated_leap_seconds,
+            SegmentLookupResult::AfterLast(last_segment) => {
+                last_segment.accumulated_leap_seconds + last_segment.leap_seconds as i32
+            }
+        }
+    }
+
+    /// Returns the number of leap seconds that have been inserted into UTC before the given
+    /// instant. A leap second that is in progress at the instant is not counted.
+    pub(crate) fn accumulated_leap_seconds_by_instant<T, S: Scale>(
+        &self,
+        instant: Instant<T, S>,
+    ) -> i32
+    where
+        T: Tick + NumCmp<i32>,
+    {
+        match self.by_instant(instant) {
+            SegmentLookupResult::BeforeFirst(_) => 0,
+            SegmentLookupResult::In(segment) => segment.accumulated_leap_seconds,
+            SegmentLookupResult::AfterLast(last_segment) => {
+                last_segment.accumulated_leap_seconds + last_segment.leap_seconds as i32
+            }
+        }
+    }
+
+    /// Returns the length in seconds of the given day (counted from the Unix epoch), taking
+    /// into account any leap second at the end of it.
+    pub(crate) fn day_length(&self, day: i32) -> u32 {
+        match self.by_day(day) {
+            SegmentLookupResult::In(segment) if day as u32 == segment.end_day() - 1 => {
+                (86_400 + segment.leap_seconds as i32) as u32
+            }
+            _ => 86_400,
+        }
+    }
 }
 
 pub(crate) fn load_leap_segments() -> Vec<ContinuousTimeSegment> {
+    try_load_leap_segments().expect("failed to read leap seconds from zoneinfo file")
+}
+
+// Like load_leap_segments, but returns None if the zoneinfo file can't be read or parsed.
+pub(crate) fn try_load_leap_segments() -> Option<Vec<ContinuousTimeSegment>> {
     // On OS:es that don't have a zoneinfo directory we likely won't be able
     // to get a list of leap seconds for each time zone. Windows, for example, only
     // tracks one set of leap seconds for all time zones.
@@ -194,8 +254,8 @@ pub(crate) fn load_leap_segments() -> Vec<ContinuousTimeSegment> {
     // time zones. In the future we will likely need to parse the leapseconds file at least
     // as a secondary strategy, because e.g. OS X does not have the "right/" directory.
     let path = tzdir().join("right/UTC");
-    let data = std::fs::read(path).expect("failed to read zoneinfo file");
-    let tz = parse(data).expect("failed to parse zoneinfo file");
+    let data = std::fs::read(path).ok()?;
+    let tz = parse(data).ok()?;
 
     let mut segments: Vec<ContinuousTimeSegment> = Vec::with_capacity(tz.leap_seconds.len());
     let mut start_instant: InstantS32 = Instant::from_ticks_since_epoch(0);
@@ -218,27 +278,56 @@ pub(crate) fn load_leap_segments() -> Vec<ContinuousTimeSegment> {
             accumulated_leap_seconds: previous_leap_second_total,
         });
 
-        start_instant = start_instant + DurationS32::new(duration_seconds);
+        start_instant += DurationS32::new(duration_seconds);
         start_day += duration_days;
         previous_leap_second_total = leap_second_total;
     }
 
-    segments
+    Some(segments)
+}
+
+// The inverse of get_leap_second_adjustment_for_unix_timestamp: the number of leap seconds to
+// subtract from the seconds since the epoch of an Instant to get a Unix timestamp. Returns None
+// if the leap-second table couldn't be loaded.
+pub(crate) fn get_leap_second_adjustment_for_instant_seconds(seconds: i64) -> Option<i32> {
+    let leap_seconds = UTC_LEAP_SECONDS.as_ref()?;
+    Some(
+        leap_seconds
+            .accumulated_leap_seconds_by_instant(InstantS64::from_ticks_since_epoch(seconds)),
+    )
 }
 
-pub(crate) fn get_leap_second_adjustment_for_unix_timestamp(unix_timestamp: i64) -> i32 {
-    // TODO can cast unix_timestamp to i32 here. If it's outside the range of the leap second array then
-    // there are obviously no more leap seconds.
-    // TODO we need to make sure this is cached and possibly even have special treatment for
-    // the leap-second chronology (which is the same for all time zones), to reduce latency.
-    // Also... iso8601 seems a bit too specific for a function like this.
-    let utc = iso8601::load_chronology("UTC");
-    let leap_seconds = utc.leap_seconds();
-
-    let day = (unix_timestamp / 86_400) as i32;
-    match leap_seconds.by_day(day) {
-        SegmentLookupResult::BeforeFirst(_) => 0,
-        SegmentLookupResult::In(segment) => segment.accumulated_leap_seconds,
-        SegmentLookupResult::AfterLast(last_segment) => last_segment.accumulated_leap_seconds,
+// Returns None if the leap-second table couldn't be loaded.
+pub(crate) fn get_leap_second_adjustment_for_unix_timestamp(unix_timestamp: i64) -> Option<i32> {
+    // Days outside the range of an i32 are far outside the leap-second table anyway, so they
+    // can be clamped.
+    let day = unix_timestamp
+        .div_euclid(86_400)
+        .clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    let leap_seconds = UTC_LEAP_SECONDS.as_ref()?;
+    Some(leap_seconds.accumulated_leap_seconds_by_day(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_second_adjustment_for_unix_timestamp() {
+        let adjustment = |unix_timestamp| {
+            get_leap_second_adjustment_for_unix_timestamp(unix_timestamp)
+                .expect("leap-second table is available")
+        };
+        // 2016-12-31, the day of the last leap second so far, and the days after it, which are
+        // after the last segment of the table.
+        assert_eq!(adjustment(1_483_142_400), 26);
+        assert_eq!(adjustment(1_483_228_800), 27);
+        assert_eq!(adjustment(1_704_067_200), 27);
+        assert_eq!(adjustment(i64::MAX), 27);
+        // Before 1972 there are no leap seconds, including for negative timestamps that would be
+        // rounded towards the epoch by a plain division.
+        assert_eq!(adjustment(0), 0);
+        assert_eq!(adjustment(-1), 0);
+        assert_eq!(adjustment(i64::MIN), 0);
     }
 }